@@ -0,0 +1,139 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+//! Cursors for the canonical byte layouts shared by the
+//! `std`/`alloc` and `no_std` types.
+//!
+//! Every `to_bytes` layout documented on `Share`, `FeldmanVerifier`,
+//! `PedersenVerifier` and `PedersenResult` is the same in `std`, `alloc`
+//! and `no_std` builds and does not depend on the serde format,
+//! so bytes written by one build are read by any other.
+
+use crate::Error;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding},
+};
+
+/// The length of the canonical encoding of a field element
+pub(crate) fn field_len<F: PrimeField>() -> usize {
+    F::Repr::default().as_ref().len()
+}
+
+/// The length of the canonical encoding of a group element
+pub(crate) fn group_len<G: GroupEncoding>() -> usize {
+    G::Repr::default().as_ref().len()
+}
+
+/// The length of a verifier with `t` commitments in each of its `sets`
+/// preceded by the threshold byte and its `generators`
+pub(crate) fn verifier_len<G: GroupEncoding>(generators: usize, sets: usize, t: usize) -> usize {
+    1 + (generators + sets * t) * group_len::<G>()
+}
+
+pub(crate) struct ByteWriter<'a> {
+    out: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    pub fn new(out: &'a mut [u8]) -> Self {
+        Self { out, pos: 0 }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let end = self.pos + bytes.len();
+        if end > self.out.len() {
+            return Err(Error::InvalidEncoding);
+        }
+        self.out[self.pos..end].copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> Result<(), Error> {
+        self.write(&[value])
+    }
+
+    /// Write a count that must fit in a byte like thresholds and share totals
+    pub fn write_count(&mut self, count: usize) -> Result<(), Error> {
+        let count = u8::try_from(count).map_err(|_| Error::InvalidEncoding)?;
+        self.write_u8(count)
+    }
+
+    pub fn write_field<F: PrimeField>(&mut self, value: &F) -> Result<(), Error> {
+        self.write(value.to_repr().as_ref())
+    }
+
+    pub fn write_group<G: GroupEncoding>(&mut self, value: &G) -> Result<(), Error> {
+        self.write(value.to_bytes().as_ref())
+    }
+
+    pub fn write_groups<G: GroupEncoding>(&mut self, values: &[G]) -> Result<(), Error> {
+        values.iter().try_for_each(|g| self.write_group(g))
+    }
+
+    /// The number of bytes written
+    pub fn finish(self) -> usize {
+        self.pos
+    }
+}
+
+pub(crate) struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub fn read(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            return Err(Error::InvalidEncoding);
+        }
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub fn read_field<F: PrimeField>(&mut self) -> Result<F, Error> {
+        let mut repr = F::Repr::default();
+        repr.as_mut().copy_from_slice(self.read(field_len::<F>())?);
+        Option::from(F::from_repr(repr)).ok_or(Error::InvalidEncoding)
+    }
+
+    pub fn read_group<G: Group + GroupEncoding>(&mut self) -> Result<G, Error> {
+        let mut repr = G::Repr::default();
+        repr.as_mut().copy_from_slice(self.read(group_len::<G>())?);
+        Option::from(G::from_bytes(&repr)).ok_or(Error::InvalidEncoding)
+    }
+
+    pub fn read_groups<G: Group + GroupEncoding>(&mut self, values: &mut [G]) -> Result<(), Error> {
+        for g in values.iter_mut() {
+            *g = self.read_group()?;
+        }
+        Ok(())
+    }
+
+    /// The remaining bytes
+    pub fn rest(self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    /// Fail if any bytes are left over
+    pub fn finish(self) -> Result<(), Error> {
+        if self.pos == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+}
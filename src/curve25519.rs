@@ -9,6 +9,7 @@
 //! to be compliant to work with this library.
 //! The intent is the consumer will not have to use these directly since
 //! the wrappers implement the [`From`] and [`Into`] traits.
+use crate::{Error, HashToGroup};
 use core::{
     borrow::Borrow,
     fmt,
@@ -32,6 +33,7 @@ use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use sha2::{Digest, Sha512};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// Wraps a ristretto25519 point
@@ -74,7 +76,7 @@ where
     }
 }
 
-impl<'a> Neg for &'a WrappedRistretto {
+impl Neg for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -98,7 +100,7 @@ impl PartialEq for WrappedRistretto {
     }
 }
 
-impl<'a, 'b> Add<&'b WrappedRistretto> for &'a WrappedRistretto {
+impl<'b> Add<&'b WrappedRistretto> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -116,7 +118,7 @@ impl<'b> Add<&'b WrappedRistretto> for WrappedRistretto {
     }
 }
 
-impl<'a> Add<WrappedRistretto> for &'a WrappedRistretto {
+impl Add<WrappedRistretto> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -148,7 +150,7 @@ impl<'b> AddAssign<&'b WrappedRistretto> for WrappedRistretto {
     }
 }
 
-impl<'a, 'b> Sub<&'b WrappedRistretto> for &'a WrappedRistretto {
+impl<'b> Sub<&'b WrappedRistretto> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -166,7 +168,7 @@ impl<'b> Sub<&'b WrappedRistretto> for WrappedRistretto {
     }
 }
 
-impl<'a> Sub<WrappedRistretto> for &'a WrappedRistretto {
+impl Sub<WrappedRistretto> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -198,7 +200,7 @@ impl<'b> SubAssign<&'b WrappedRistretto> for WrappedRistretto {
     }
 }
 
-impl<'a, 'b> Mul<&'b WrappedScalar> for &'a WrappedRistretto {
+impl<'b> Mul<&'b WrappedScalar> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -216,7 +218,7 @@ impl<'b> Mul<&'b WrappedScalar> for WrappedRistretto {
     }
 }
 
-impl<'a> Mul<WrappedScalar> for &'a WrappedRistretto {
+impl Mul<WrappedScalar> for &WrappedRistretto {
     type Output = WrappedRistretto;
 
     #[inline]
@@ -268,6 +270,14 @@ impl GroupEncoding for WrappedRistretto {
     }
 }
 
+impl HashToGroup for WrappedRistretto {
+    /// ristretto255_XMD:SHA-512_R255MAP_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        let uniform = expand_message_xmd_sha512(msg, dst)?;
+        Ok(Self(RistrettoPoint::from_uniform_bytes(&uniform)))
+    }
+}
+
 impl Default for WrappedRistretto {
     fn default() -> Self {
         Self(RistrettoPoint::identity())
@@ -338,7 +348,7 @@ impl Group for WrappedEdwards {
     fn random(mut rng: impl RngCore) -> Self {
         let mut seed = [0u8; 32];
         rng.fill_bytes(&mut seed);
-        Self(EdwardsPoint::hash_from_bytes::<sha2::Sha512>(&seed))
+        Self(EdwardsPoint::hash_from_bytes::<Sha512>(&seed))
     }
 
     fn identity() -> Self {
@@ -367,7 +377,7 @@ where
     }
 }
 
-impl<'a> Neg for &'a WrappedEdwards {
+impl Neg for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -391,7 +401,7 @@ impl PartialEq for WrappedEdwards {
     }
 }
 
-impl<'a, 'b> Add<&'b WrappedEdwards> for &'a WrappedEdwards {
+impl<'b> Add<&'b WrappedEdwards> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -409,7 +419,7 @@ impl<'b> Add<&'b WrappedEdwards> for WrappedEdwards {
     }
 }
 
-impl<'a> Add<WrappedEdwards> for &'a WrappedEdwards {
+impl Add<WrappedEdwards> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -441,7 +451,7 @@ impl<'b> AddAssign<&'b WrappedEdwards> for WrappedEdwards {
     }
 }
 
-impl<'a, 'b> Sub<&'b WrappedEdwards> for &'a WrappedEdwards {
+impl<'b> Sub<&'b WrappedEdwards> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -459,7 +469,7 @@ impl<'b> Sub<&'b WrappedEdwards> for WrappedEdwards {
     }
 }
 
-impl<'a> Sub<WrappedEdwards> for &'a WrappedEdwards {
+impl Sub<WrappedEdwards> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -491,7 +501,7 @@ impl<'b> SubAssign<&'b WrappedEdwards> for WrappedEdwards {
     }
 }
 
-impl<'a, 'b> Mul<&'b WrappedScalar> for &'a WrappedEdwards {
+impl<'b> Mul<&'b WrappedScalar> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -509,7 +519,7 @@ impl<'b> Mul<&'b WrappedScalar> for WrappedEdwards {
     }
 }
 
-impl<'a> Mul<WrappedScalar> for &'a WrappedEdwards {
+impl Mul<WrappedScalar> for &WrappedEdwards {
     type Output = WrappedEdwards;
 
     #[inline]
@@ -561,6 +571,19 @@ impl GroupEncoding for WrappedEdwards {
     }
 }
 
+impl HashToGroup for WrappedEdwards {
+    /// curve25519-dalek doesn't expose the elligator 2 map required by
+    /// edwards25519_XMD:SHA-512_ELL2_RO_ so this uses expand_message_xmd
+    /// followed by the dalek elligator map and clears the cofactor.
+    /// This is not RFC 9380 compatible.
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        let uniform = expand_message_xmd_sha512(msg, dst)?;
+        Ok(Self(
+            EdwardsPoint::hash_from_bytes::<Sha512>(&uniform).mul_by_cofactor(),
+        ))
+    }
+}
+
 impl Default for WrappedEdwards {
     fn default() -> Self {
         Self(EdwardsPoint::identity())
@@ -735,7 +758,7 @@ impl PartialEq for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Add<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Add<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -753,7 +776,7 @@ impl<'b> Add<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Add<WrappedScalar> for &'a WrappedScalar {
+impl Add<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -785,7 +808,7 @@ impl<'b> AddAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Sub<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Sub<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -803,7 +826,7 @@ impl<'b> Sub<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Sub<WrappedScalar> for &'a WrappedScalar {
+impl Sub<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -835,7 +858,7 @@ impl<'b> SubAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Mul<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Mul<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -853,7 +876,7 @@ impl<'b> Mul<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Mul<WrappedScalar> for &'a WrappedScalar {
+impl Mul<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -885,7 +908,7 @@ impl<'b> MulAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Neg for &'a WrappedScalar {
+impl Neg for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -956,7 +979,7 @@ impl<'de> Deserialize<'de> for WrappedScalar {
 
 #[test]
 fn ristretto_to_edwards() {
-    let mut osrng = rand_7::rngs::OsRng::default();
+    let mut osrng = rand_7::rngs::OsRng;
     let sk = Scalar::random(&mut osrng);
     let pk = RISTRETTO_BASEPOINT_POINT * sk;
     let ek = WrappedEdwards::from(WrappedRistretto(pk));
@@ -965,7 +988,7 @@ fn ristretto_to_edwards() {
 
 #[test]
 fn serde_scalar() {
-    let rng = rand::rngs::OsRng::default();
+    let rng = rand::rngs::OsRng;
     let ws1 = WrappedScalar::random(rng);
     // serialize
     let res = serde_bare::to_vec(&ws1);
@@ -980,7 +1003,7 @@ fn serde_scalar() {
 
 #[test]
 fn serde_edwards() {
-    let rng = rand::rngs::OsRng::default();
+    let rng = rand::rngs::OsRng;
     let ed1 = WrappedEdwards::random(rng);
     // serialize
     let res = serde_bare::to_vec(&ed1);
@@ -992,3 +1015,42 @@ fn serde_edwards() {
     let ed2: WrappedEdwards = res.unwrap();
     assert_eq!(ed1, ed2);
 }
+
+/// RFC 9380 expand_message_xmd with SHA-512 producing 64 bytes
+pub(crate) fn expand_message_xmd_sha512(msg: &[u8], dst: &[u8]) -> Result<[u8; 64], Error> {
+    const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+    const LEN_IN_BYTES: [u8; 2] = [0, 64];
+
+    if dst.is_empty() {
+        return Err(Error::InvalidGenerator);
+    }
+    let hashed_dst;
+    let dst = if dst.len() > 255 {
+        hashed_dst = Sha512::new().chain(OVERSIZE_DST_SALT).chain(dst).finalize();
+        hashed_dst.as_slice()
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha512::new()
+        .chain([0u8; 128])
+        .chain(msg)
+        .chain(LEN_IN_BYTES)
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let b_1 = Sha512::new()
+        .chain(b_0)
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&b_1);
+    Ok(output)
+}
@@ -0,0 +1,123 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use crate::Error;
+use core::convert::TryFrom;
+
+/// Identifies the field or group a share, verifier or commitment belongs to
+/// so values from different curves can't be mixed up.
+///
+/// Ed25519 and ristretto255 share the [`CurveId::Curve25519`] scalar field and
+/// BLS12-381 G1 and G2 share the [`CurveId::Bls12381`] scalar field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum CurveId {
+    /// The secp256k1 scalar field and group
+    Secp256k1 = 1,
+    /// The NIST P-256 scalar field and group
+    P256 = 2,
+    /// The scalar field of Ed25519 and ristretto255
+    Curve25519 = 3,
+    /// The Ed25519 group
+    Ed25519 = 4,
+    /// The ristretto255 group
+    Ristretto25519 = 5,
+    /// The BLS12-381 scalar field
+    Bls12381 = 6,
+    /// The BLS12-381 G1 group
+    Bls12381G1 = 7,
+    /// The BLS12-381 G2 group
+    Bls12381G2 = 8,
+}
+
+impl CurveId {
+    /// A short lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Secp256k1 => "secp256k1",
+            Self::P256 => "p256",
+            Self::Curve25519 => "curve25519",
+            Self::Ed25519 => "ed25519",
+            Self::Ristretto25519 => "ristretto25519",
+            Self::Bls12381 => "bls12381",
+            Self::Bls12381G1 => "bls12381g1",
+            Self::Bls12381G2 => "bls12381g2",
+        }
+    }
+
+    /// The arcs of the object identifier of this curve.
+    ///
+    /// These are the registered OIDs for secp256k1, P-256 and Ed25519.
+    /// The others have no registered OID so UUID based OIDs under `2.25`
+    /// from ITU-T X.667 are used.
+    pub fn oid(&self) -> &'static [u128] {
+        match self {
+            Self::Secp256k1 => &[1, 3, 132, 0, 10],
+            Self::P256 => &[1, 2, 840, 10045, 3, 1, 7],
+            Self::Curve25519 => &[2, 25, 314772507487439001203886816304592035246],
+            Self::Ed25519 => &[1, 3, 101, 112],
+            Self::Ristretto25519 => &[2, 25, 330489631523883435379117975341129038749],
+            Self::Bls12381 => &[2, 25, 134309039555292031568477596129598717145],
+            Self::Bls12381G1 => &[2, 25, 230336942855578680577132753269780151507],
+            Self::Bls12381G2 => &[2, 25, 307513855527793226957682603531733331270],
+        }
+    }
+}
+
+impl TryFrom<u8> for CurveId {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Error> {
+        match value {
+            1 => Ok(Self::Secp256k1),
+            2 => Ok(Self::P256),
+            3 => Ok(Self::Curve25519),
+            4 => Ok(Self::Ed25519),
+            5 => Ok(Self::Ristretto25519),
+            6 => Ok(Self::Bls12381),
+            7 => Ok(Self::Bls12381G1),
+            8 => Ok(Self::Bls12381G2),
+            _ => Err(Error::InvalidEnvelope),
+        }
+    }
+}
+
+/// Fields and groups with a [`CurveId`]
+pub trait CurveIdentifier {
+    /// The identifier of this field or group
+    const CURVE_ID: CurveId;
+}
+
+#[allow(unused_macros)]
+macro_rules! curve_identifier {
+    ($($ty:ty => $id:ident),+ $(,)?) => {
+        $(
+            impl CurveIdentifier for $ty {
+                const CURVE_ID: CurveId = CurveId::$id;
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "secp256k1", test))]
+curve_identifier!(k256::Scalar => Secp256k1, k256::ProjectivePoint => Secp256k1);
+#[cfg(feature = "secp256k1")]
+curve_identifier!(
+    crate::secp256k1::WrappedScalar => Secp256k1,
+    crate::secp256k1::WrappedProjectivePoint => Secp256k1,
+);
+#[cfg(any(feature = "p256", test))]
+curve_identifier!(p256::Scalar => P256, p256::ProjectivePoint => P256);
+#[cfg(feature = "curve25519")]
+curve_identifier!(
+    crate::curve25519::WrappedScalar => Curve25519,
+    crate::curve25519::WrappedEdwards => Ed25519,
+    crate::curve25519::WrappedRistretto => Ristretto25519,
+);
+#[cfg(any(feature = "bls12_381", test))]
+curve_identifier!(
+    bls12_381_plus::Scalar => Bls12381,
+    bls12_381_plus::G1Projective => Bls12381G1,
+    bls12_381_plus::G2Projective => Bls12381G2,
+);
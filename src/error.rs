@@ -24,6 +24,22 @@ pub enum Error {
     InvalidSecret,
     /// A share cannot be converted to a group or field element
     InvalidShareConversion,
+    /// Verifiers with different generators cannot be combined
+    InvalidGenerator,
+    /// Verifiers with different thresholds cannot be combined
+    SharingThresholdMismatch,
+    /// A protocol method was called out of order
+    InvalidRound,
+    /// A zero knowledge proof failed to verify
+    InvalidProof,
+    /// A signature or signature share failed to verify
+    InvalidSignature,
+    /// A ciphertext was not authentic or could not be decrypted
+    InvalidCiphertext,
+    /// A share envelope is malformed or belongs to another sharing
+    InvalidEnvelope,
+    /// A text or binary encoding is malformed or fails its checksum
+    InvalidEncoding,
     /// A specific function is not implemented
     NotImplemented,
 }
@@ -47,6 +63,21 @@ impl Display for Error {
             Self::InvalidShareConversion => {
                 write!(f, "A share cannot be converted to a group or field element")
             }
+            Self::InvalidGenerator => write!(f, "The verifier generators do not match"),
+            Self::SharingThresholdMismatch => write!(f, "The verifier thresholds do not match"),
+            Self::InvalidRound => write!(f, "A protocol method was called out of order"),
+            Self::InvalidProof => write!(f, "A zero knowledge proof failed to verify"),
+            Self::InvalidSignature => write!(f, "A signature failed to verify"),
+            Self::InvalidCiphertext => write!(f, "A ciphertext failed to decrypt"),
+            Self::InvalidEnvelope => {
+                write!(
+                    f,
+                    "A share envelope is malformed or belongs to another sharing"
+                )
+            }
+            Self::InvalidEncoding => {
+                write!(f, "An encoding is malformed or fails its checksum")
+            }
             Self::NotImplemented => write!(f, "Not implemented"),
         }
     }
@@ -0,0 +1,64 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use crate::Error;
+use elliptic_curve::group::{Group, GroupEncoding};
+
+/// The domain separation tag used to derive the default Pedersen blinding generator
+pub const PEDERSEN_BLINDING_GENERATOR_DST: &[u8] = b"VSSS-RS-V01-PEDERSEN-BLINDING-GENERATOR";
+
+/// Groups that can hash arbitrary bytes to a group element such that
+/// nobody knows its discrete log with respect to the group generator.
+///
+/// Where possible this is the random oracle hash to curve from RFC 9380
+/// for the curve's suite.
+pub trait HashToGroup: Group + GroupEncoding {
+    /// Hash `msg` to a group element using the domain separation tag `dst`.
+    /// `dst` cannot be empty.
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error>;
+
+    /// The nothing-up-my-sleeve generator used for Pedersen blinding factors
+    /// when the dealer doesn't supply one.
+    fn blinding_generator() -> Self {
+        Self::hash_to_group(&[], PEDERSEN_BLINDING_GENERATOR_DST)
+            .expect("the blinding generator DST is not empty")
+    }
+}
+
+#[cfg(any(feature = "p256", test))]
+impl HashToGroup for p256::ProjectivePoint {
+    /// P256_XMD:SHA-256_SSWU_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        use elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+
+        p256::NistP256::hash_from_bytes::<ExpandMsgXmd<sha2_10::Sha256>>(&[msg], dst)
+            .map_err(|_| Error::InvalidGenerator)
+    }
+}
+
+#[cfg(any(feature = "bls12_381", test))]
+impl HashToGroup for bls12_381_plus::G1Projective {
+    /// BLS12381G1_XMD:SHA-256_SSWU_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        if dst.is_empty() {
+            return Err(Error::InvalidGenerator);
+        }
+        Ok(Self::hash::<bls12_381_plus::ExpandMsgXmd<sha2::Sha256>>(
+            msg, dst,
+        ))
+    }
+}
+
+#[cfg(any(feature = "bls12_381", test))]
+impl HashToGroup for bls12_381_plus::G2Projective {
+    /// BLS12381G2_XMD:SHA-256_SSWU_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        if dst.is_empty() {
+            return Err(Error::InvalidGenerator);
+        }
+        Ok(Self::hash::<bls12_381_plus::ExpandMsgXmd<sha2::Sha256>>(
+            msg, dst,
+        ))
+    }
+}
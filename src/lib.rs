@@ -12,6 +12,29 @@
 //! This crate supports Feldman and Pedersen verifiable secret sharing
 //! schemes.
 //!
+//! Additive (n-of-n) sharing is also provided along with conversions
+//! to and from Shamir shares as used by most MPC protocols.
+//!
+//! With `std` or `alloc`, `GjkrParticipant` runs the Gennaro, Jarecki, Krawczyk, Rabin
+//! distributed key generation built on Pedersen and Feldman, and
+//! `FrostKeygenParticipant` runs the FROST key generation. `Frost` produces
+//! RFC 9591 threshold Schnorr signatures with the resulting key packages.
+//! With the `bls12_381` feature `ThresholdBls` produces threshold BLS signatures.
+//! `ElGamalCiphertext` supports threshold decryption with verifiable decryption shares.
+//! It is homomorphic and only secure against chosen plaintext attacks.
+//! `Pvss` deals shares encrypted to the recipients' public keys that anyone can verify.
+//! `EncryptedShare` encrypts shares to their holders bound to the dealer's verifier hash.
+//! `DealerSignature` lets receivers check verifiers and share envelopes came from the expected dealer.
+//! `SchnorrProof`, `DleqProof` and `OkamotoProof` are reusable sigma protocol proofs.
+//! `ShareComplaint` proves a dealer sent a bad share so anyone can judge the dispute.
+//! `Bivariate` sharing lets participants cross-check their shares pairwise without commitments.
+//! With the `bls12_381` feature `Kzg` commits to the sharing with one G1 element and constant-size openings.
+//! `CheckedShamir` adds unconditionally secure integrity checks to plain Shamir shares.
+//! `ShareEnvelope` records which curve, scheme and split a share belongs to.
+//! Shares and verifiers have a bech32m text form with a checksum for reading aloud or writing down.
+//! With the `der` feature shares and verifiers encode to DER and PEM with curve OIDs from `CurveId::oid`.
+//! `to_bytes`/`from_bytes` give shares, verifiers and Pedersen results one byte layout independent of serde, with `to_bytes_into` for `no_std` buffers.
+//!
 //! Feldman and Pedersen are similar in many ways. It's hard to describe when to use
 //! one over the other. Indeed both are used in
 //! <http://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.134.6445&rep=rep1&type=pdf>.
@@ -178,16 +201,20 @@ extern crate std;
 
 mod lib {
     #[cfg(all(feature = "alloc", not(feature = "std")))]
-    pub use alloc::collections::BTreeSet;
+    pub use alloc::collections::{BTreeMap, BTreeSet};
     #[cfg(all(feature = "alloc", not(feature = "std")))]
     pub use alloc::string::String;
     #[cfg(all(feature = "alloc", not(feature = "std")))]
+    pub use alloc::vec;
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
     pub use alloc::vec::Vec;
     #[cfg(feature = "std")]
-    pub use std::collections::BTreeSet;
+    pub use std::collections::{BTreeMap, BTreeSet};
     #[cfg(feature = "std")]
     pub use std::string::String;
     #[cfg(feature = "std")]
+    pub use std::vec;
+    #[cfg(feature = "std")]
     pub use std::vec::Vec;
 }
 
@@ -199,7 +226,10 @@ mod tests;
 pub mod curve25519;
 #[cfg(feature = "curve25519")]
 pub use curve25519_dalek;
+mod codec;
+mod curve_id;
 mod error;
+mod hash_to_group;
 #[cfg(all(not(feature = "std"), not(feature = "alloc")))]
 mod no_std;
 #[cfg(feature = "secp256k1")]
@@ -218,7 +248,11 @@ mod util;
 
 use util::*;
 
+pub use util::sum_of_products;
+
+pub use curve_id::*;
 pub use error::*;
+pub use hash_to_group::*;
 #[cfg(all(not(feature = "std"), not(feature = "alloc")))]
 pub use no_std::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -1,20 +1,24 @@
+mod additive;
 mod feldman;
 mod pedersen;
 mod polynomial;
+mod ramp;
 mod shamir;
 mod share;
 mod verifier;
 
+pub use additive::*;
 pub use feldman::*;
 pub use pedersen::*;
 pub use polynomial::*;
+pub use ramp::*;
 pub use shamir::*;
 pub use share::*;
 pub use verifier::*;
 
 use crate::util::*;
 use core::{
-    fmt::{self, Formatter},
+    fmt::{self, Display, Formatter},
     marker::PhantomData,
     mem::MaybeUninit,
 };
@@ -24,13 +28,28 @@ use elliptic_curve::{
 };
 use serde::{
     de::{Error, SeqAccess, Unexpected, Visitor},
-    ser::{self, SerializeSeq, SerializeTuple},
-    Deserializer, Serializer,
+    ser::{SerializeSeq, SerializeTuple},
+    Deserializer, Serialize, Serializer,
 };
 
-/// Set to BLS12-381. If we need bigger, then adjust
-const MAX_GROUP_BYTES: usize = 192;
-const MAX_GROUP_HEX: usize = MAX_GROUP_BYTES * 2;
+/// Lowercase hex written straight to the serializer one byte at a time
+/// so values of any size are serialized without a buffer or allocation
+pub(crate) struct Hex<'a>(pub &'a [u8]);
+
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for Hex<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(self)
+    }
+}
 
 pub(crate) fn serialize_scalar<F: PrimeField, S: Serializer>(
     scalar: &F,
@@ -104,12 +123,7 @@ pub(crate) fn serialize_group<G: Group + GroupEncoding, S: Serializer>(
 fn serialize_ref<B: AsRef<[u8]>, S: Serializer>(bytes: B, s: S) -> Result<S::Ok, S::Error> {
     let bytes = bytes.as_ref();
     if s.is_human_readable() {
-        let mut output = [0u8; MAX_GROUP_HEX];
-        let len = bytes.len();
-        hex::encode_to_slice(bytes, &mut output[..len * 2])
-            .map_err(|_| ser::Error::custom("invalid length"))?;
-        let h = unsafe { core::str::from_utf8_unchecked(&output[..len * 2]) };
-        s.serialize_str(h)
+        s.collect_str(&Hex(bytes))
     } else {
         let mut tupler = s.serialize_tuple(bytes.len())?;
         for b in bytes {
@@ -127,15 +141,8 @@ pub(crate) fn serialize_group_vec<G: Group + GroupEncoding, S: Serializer, const
     let mut sequencer;
     if is_human_readable {
         sequencer = s.serialize_seq(Some(N))?;
-        let mut out = [0u8; MAX_GROUP_HEX];
         for gg in g {
-            let repr = gg.to_bytes();
-            let bytes = repr.as_ref();
-            let len = bytes.len();
-            hex::encode_to_slice(bytes, &mut out[..len * 2])
-                .map_err(|_| ser::Error::custom("invalid length"))?;
-            let h = unsafe { core::str::from_utf8_unchecked(&out[..len * 2]) };
-            sequencer.serialize_element(h)?;
+            sequencer.serialize_element(&Hex(gg.to_bytes().as_ref()))?;
         }
     } else {
         let len = uint_zigzag::Uint::from(g.len());
@@ -0,0 +1,143 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use crate::util::{bytes_to_field, lagrange};
+use crate::{Error, Feldman, FeldmanVerifier, Share};
+use core::mem::MaybeUninit;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+
+/// Additive (n-of-n) secret sharing scheme.
+/// N is the total number of shares
+///
+/// The secret is the sum of all the shares so every share is
+/// required to reconstruct it. Most MPC protocols operate on additive
+/// shares, so this also provides conversions to and from Shamir shares.
+///
+/// To convert Shamir shares, each signer in a subset calls `from_shamir_share`
+/// with the identifiers in the subset. The resulting shares are additive shares
+/// of the same secret among that subset.
+///
+/// To convert back, each party calls `to_shamir` to reshare its additive share
+/// using Feldman, sends the results to the other parties who then
+/// call `combine_reshares` with everything they received.
+#[derive(Copy, Clone, Debug)]
+pub struct Additive<const N: usize>;
+
+impl<const N: usize> Additive<N> {
+    /// Create shares from a secret.
+    /// F is the prime field
+    /// S is the number of bytes used to represent F
+    pub fn split_secret<F, R, const S: usize>(
+        secret: F,
+        rng: &mut R,
+    ) -> Result<[Share<S>; N], Error>
+    where
+        F: PrimeField,
+        R: RngCore + CryptoRng,
+    {
+        Self::check_params()?;
+
+        let mut shares: MaybeUninit<[Share<S>; N]> = MaybeUninit::uninit();
+        let mut sum = F::zero();
+        for i in 0..N {
+            let value = if i == N - 1 {
+                secret - sum
+            } else {
+                F::random(&mut *rng)
+            };
+            sum += value;
+            let share = Share::from_field_element((i + 1) as u8, value)?;
+
+            let p = (shares.as_mut_ptr() as *mut Share<S>).wrapping_add(i);
+            unsafe { core::ptr::write(p, share) };
+        }
+        let shares = unsafe { shares.assume_init() };
+        Ok(shares)
+    }
+
+    /// Reconstruct a secret from all the shares created from `split_secret`.
+    pub fn combine_shares<F, const S: usize>(shares: &[Share<S>]) -> Result<F, Error>
+    where
+        F: PrimeField,
+    {
+        Self::check_params()?;
+
+        if shares.len() != N {
+            return Err(Error::SharingMinThreshold);
+        }
+
+        let mut dups = [false; N];
+        let mut secret = F::zero();
+        for s in shares {
+            let identifier = s.identifier();
+            if identifier == 0 || identifier as usize > N {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if dups[identifier as usize - 1] {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            dups[identifier as usize - 1] = true;
+            secret += bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+        }
+        Ok(secret)
+    }
+
+    /// Convert a Shamir share into an additive share by multiplying
+    /// it with its lagrange coefficient for the `participants`.
+    ///
+    /// The additive shares of all `participants` sum to the Shamir secret.
+    pub fn from_shamir_share<F, const S: usize>(
+        share: &Share<S>,
+        participants: &[u8],
+    ) -> Result<Share<S>, Error>
+    where
+        F: PrimeField,
+    {
+        let value = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare)?;
+        let coefficient = lagrange::<F>(share.identifier(), participants)?;
+        Share::from_field_element(share.identifier(), value * coefficient)
+    }
+
+    /// Reshare an additive share with Feldman to begin converting it
+    /// back into a `T` of `M` Shamir share.
+    ///
+    /// Each resulting share and the verifier are sent to the party
+    /// with the same identifier.
+    pub fn to_shamir<F, G, R, const T: usize, const M: usize, const S: usize>(
+        share: &Share<S>,
+        generator: Option<G>,
+        rng: &mut R,
+    ) -> Result<([Share<S>; M], FeldmanVerifier<F, G, T>), Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + Default + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let value = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare)?;
+        Feldman::<T, M>::split_secret(value, generator, rng)
+    }
+
+    /// Complete the conversion to Shamir by summing the reshares
+    /// this party received from every additive share holder.
+    pub fn combine_reshares<F, const S: usize>(shares: &[Share<S>]) -> Result<Share<S>, Error>
+    where
+        F: PrimeField,
+    {
+        Share::sum_field_elements::<F>(shares)
+    }
+
+    pub(crate) fn check_params() -> Result<(), Error> {
+        if N < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if N > 255 {
+            return Err(Error::SharingMaxRequest);
+        }
+        Ok(())
+    }
+}
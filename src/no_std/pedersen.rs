@@ -4,7 +4,8 @@
 */
 
 use super::{deserialize_scalar, serialize_scalar, share::Share};
-use crate::{Error, FeldmanVerifier, PedersenVerifier, Shamir};
+use crate::codec::{field_len, ByteReader, ByteWriter};
+use crate::{Error, FeldmanVerifier, HashToGroup, PedersenVerifier, Shamir};
 use core::fmt::Formatter;
 use core::marker::PhantomData;
 use elliptic_curve::{
@@ -90,6 +91,67 @@ fn deserialize_shares_array<'de, D: Deserializer<'de>, const S: usize, const N:
     d.deserialize_tuple(N, ShareArrayVisitor)
 }
 
+impl<
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        const S: usize,
+        const T: usize,
+        const N: usize,
+    > PedersenResult<F, G, S, T, N>
+{
+    /// The length of [`PedersenResult::to_bytes_into`]
+    pub fn bytes_len(&self) -> usize {
+        field_len::<F>() + 1 + 2 * N * S + self.verifier.bytes_len()
+    }
+
+    /// Write the canonical encoding, the blinding factor in the canonical
+    /// encoding of `F`, the number of shares `N` as one byte, the `N` blind
+    /// shares, the `N` secret shares then [`PedersenVerifier::to_bytes_into`],
+    /// into `out` returning the number of bytes written.
+    /// Each share is [`Share::to_bytes_into`] with a field element value.
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if S != 1 + field_len::<F>() {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut writer = ByteWriter::new(out);
+        writer.write_field(&self.blinding)?;
+        writer.write_count(N)?;
+        for share in self.blind_shares.iter().chain(self.secret_shares.iter()) {
+            writer.write(&share.0)?;
+        }
+        let mut len = writer.finish();
+        len += self.verifier.to_bytes_into(&mut out[len..])?;
+        Ok(len)
+    }
+
+    /// Decode a result written with [`PedersenResult::to_bytes_into`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if S != 1 + field_len::<F>() {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut reader = ByteReader::new(bytes);
+        let blinding = reader.read_field()?;
+        if reader.read_u8()? as usize != N {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut blind_shares = [Share::<S>::default(); N];
+        let mut secret_shares = [Share::<S>::default(); N];
+        for share in blind_shares.iter_mut().chain(secret_shares.iter_mut()) {
+            *share = Share::from_bytes(reader.read(S)?)?;
+            share
+                .as_field_element::<F>()
+                .map_err(|_| Error::InvalidEncoding)?;
+        }
+        let verifier = PedersenVerifier::from_bytes(reader.rest())?;
+        Ok(Self {
+            blinding,
+            blind_shares,
+            secret_shares,
+            verifier,
+        })
+    }
+}
+
 /// Pedersen's Verifiable secret sharing scheme.
 /// (see <https://www.cs.cornell.edu/courses/cs754/2001fa/129.PDF>)
 ///
@@ -111,7 +173,8 @@ impl<const T: usize, const N: usize> Pedersen<T, N> {
     /// `share_generator` is the generator point to use for shares.
     /// If [`None`], the default generator is used.
     /// `blind_factor_generator` is the generator point to use for blinding factor shares.
-    /// If [`None`], a random generator is used
+    /// If [`None`], [`HashToGroup::blinding_generator`] is used so nobody
+    /// knows its discrete log with respect to the share generator.
     pub fn split_secret<F, G, R, const S: usize>(
         secret: F,
         blinding: Option<F>,
@@ -121,7 +184,7 @@ impl<const T: usize, const N: usize> Pedersen<T, N> {
     ) -> Result<PedersenResult<F, G, S, T, N>, Error>
     where
         F: PrimeField,
-        G: Group + GroupEncoding + Default + ScalarMul<F>,
+        G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
         R: RngCore + CryptoRng,
     {
         Shamir::<T, N>::check_params()?;
@@ -131,8 +194,7 @@ impl<const T: usize, const N: usize> Pedersen<T, N> {
         let mut crng = ChaChaRng::from_seed(seed);
 
         let g = share_generator.unwrap_or_else(G::generator);
-        let t = F::random(&mut crng);
-        let h = blind_factor_generator.unwrap_or_else(|| G::generator() * t);
+        let h = blind_factor_generator.unwrap_or_else(G::blinding_generator);
 
         let blinding = blinding.unwrap_or_else(|| F::random(&mut crng));
         let (secret_shares, secret_polynomial) =
@@ -0,0 +1,160 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use crate::{bytes_to_field, Error, Polynomial, Share};
+use core::mem::MaybeUninit;
+use elliptic_curve::ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+
+/// Ramp (non-perfect) secret sharing scheme.
+/// T is the privacy threshold
+/// R is the reconstruction threshold
+/// N is the total number of shares
+///
+/// Any `T` or fewer shares reveal nothing about the secret.
+/// Any `R` shares reconstruct the secret.
+/// Sets of shares in between leak partial information.
+///
+/// The secret is `R - T` field elements long while each share is still a single
+/// field element, so storage overhead shrinks as the gap between thresholds grows.
+/// With `R - T = 1` this is the same as Shamir with threshold `R`.
+///
+/// The secret is placed in the low coefficients of a degree `R - 1`
+/// polynomial and the remaining `T` coefficients are random.
+#[derive(Copy, Clone, Debug)]
+pub struct Ramp<const T: usize, const R: usize, const N: usize>;
+
+impl<const T: usize, const R: usize, const N: usize> Ramp<T, R, N> {
+    /// Create shares from a secret of `L = R - T` field elements.
+    /// F is the prime field
+    /// S is the number of bytes used to represent F
+    pub fn split_secret<F, RNG, const S: usize, const L: usize>(
+        secret: &[F; L],
+        rng: &mut RNG,
+    ) -> Result<[Share<S>; N], Error>
+    where
+        F: PrimeField,
+        RNG: RngCore + CryptoRng,
+    {
+        Self::check_params()?;
+        if L + T != R {
+            return Err(Error::InvalidSecret);
+        }
+
+        let mut coefficients = [F::default(); R];
+        coefficients[..L].copy_from_slice(secret);
+        for c in coefficients.iter_mut().skip(L) {
+            *c = F::random(&mut *rng);
+        }
+        let polynomial = Polynomial { coefficients };
+
+        let mut shares: MaybeUninit<[Share<S>; N]> = MaybeUninit::uninit();
+        let mut x = F::one();
+        for i in 0..N {
+            let y = polynomial.evaluate(x);
+            let share = Share::from_field_element((i + 1) as u8, y)?;
+
+            let p = (shares.as_mut_ptr() as *mut Share<S>).wrapping_add(i);
+            unsafe { core::ptr::write(p, share) };
+
+            x += F::one();
+        }
+        let shares = unsafe { shares.assume_init() };
+        Ok(shares)
+    }
+
+    /// Reconstruct a secret of `L = R - T` field elements from
+    /// at least `R` shares created from `split_secret`.
+    pub fn combine_shares<F, const S: usize, const L: usize>(
+        shares: &[Share<S>],
+    ) -> Result<[F; L], Error>
+    where
+        F: PrimeField,
+    {
+        Self::check_params()?;
+        if L + T != R {
+            return Err(Error::InvalidSecret);
+        }
+
+        if shares.len() < R {
+            return Err(Error::SharingMinThreshold);
+        }
+
+        let mut dups = [false; N];
+        let mut x_coordinates = [F::default(); R];
+        let mut y_coordinates = [F::default(); R];
+        for (i, s) in shares.iter().enumerate().take(R) {
+            let identifier = s.identifier();
+            if identifier == 0 || identifier as usize > N {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if dups[identifier as usize - 1] {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            dups[identifier as usize - 1] = true;
+            x_coordinates[i] = F::from(identifier as u64);
+            y_coordinates[i] = bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+        }
+
+        let coefficients = Self::interpolate(&x_coordinates, &y_coordinates);
+        let mut secret = [F::default(); L];
+        secret.copy_from_slice(&coefficients[..L]);
+        Ok(secret)
+    }
+
+    /// Recover all coefficients of the polynomial through the points.
+    fn interpolate<F: PrimeField>(x_coordinates: &[F; R], y_coordinates: &[F; R]) -> [F; R] {
+        // The monic polynomial (x - x_0)(x - x_1)...(x - x_{r-1})
+        // without its leading coefficient
+        let mut master = [F::zero(); R];
+        for (d, x) in x_coordinates.iter().enumerate() {
+            // multiply by (x - x_d) where the implicit leading one is at position d
+            master[d] = if d == 0 { -*x } else { master[d - 1] - *x };
+            for k in (1..d).rev() {
+                master[k] = master[k - 1] - master[k] * x;
+            }
+            if d > 0 {
+                master[0] = -master[0] * x;
+            }
+        }
+
+        let mut coefficients = [F::zero(); R];
+        let mut basis = [F::zero(); R];
+        for i in 0..R {
+            // master / (x - x_i) by synthetic division
+            basis[R - 1] = F::one();
+            for k in (1..R).rev() {
+                basis[k - 1] = master[k] + x_coordinates[i] * basis[k];
+            }
+
+            let mut denom = F::one();
+            for j in 0..R {
+                if i != j {
+                    denom *= x_coordinates[i] - x_coordinates[j];
+                }
+            }
+            let scale = y_coordinates[i] * denom.invert().unwrap();
+            for (c, b) in coefficients.iter_mut().zip(basis.iter()) {
+                *c += *b * scale;
+            }
+        }
+        coefficients
+    }
+
+    pub(crate) fn check_params() -> Result<(), Error> {
+        if T < 1 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if R <= T {
+            return Err(Error::SharingMinThreshold);
+        }
+        if N < R {
+            return Err(Error::SharingLimitLessThanThreshold);
+        }
+        if N > 255 {
+            return Err(Error::SharingMaxRequest);
+        }
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
     Copyright Michael Lodder. All Rights Reserved.
     SPDX-License-Identifier: Apache-2.0
 */
-use crate::util::bytes_to_group;
+use crate::util::{bytes_to_group, sum_of_products};
 use crate::{bytes_to_field, Error, Polynomial, Share};
 use core::{
     mem::MaybeUninit,
@@ -45,7 +45,8 @@ impl<const T: usize, const N: usize> Shamir<T, N> {
     where
         F: PrimeField,
     {
-        Self::combine::<F, F, S>(shares, bytes_to_field)
+        let (x_coordinates, y_coordinates) = Self::coordinates::<F, F, S>(shares, bytes_to_field)?;
+        Ok(Self::interpolate(&x_coordinates, &y_coordinates))
     }
 
     /// Reconstruct a secret from shares created from `split_secret`.
@@ -59,16 +60,20 @@ impl<const T: usize, const N: usize> Shamir<T, N> {
         F: PrimeField,
         G: Group + GroupEncoding + ScalarMul<F> + Default,
     {
-        Self::combine::<F, G, S>(shares, bytes_to_group)
+        let (x_coordinates, y_coordinates) = Self::coordinates::<F, G, S>(shares, bytes_to_group)?;
+        // The shares and lagrange coefficients are public so
+        // use a multi-scalar multiplication
+        let basis = Self::lagrange_basis(&x_coordinates);
+        Ok(sum_of_products(&y_coordinates, &basis))
     }
 
-    fn combine<F, S, const SS: usize>(
+    fn coordinates<F, S, const SS: usize>(
         shares: &[Share<SS>],
         f: fn(&[u8]) -> Option<S>,
-    ) -> Result<S, Error>
+    ) -> Result<([F; T], [S; T]), Error>
     where
         F: PrimeField,
-        S: Default + Copy + AddAssign + Mul<F, Output = S>,
+        S: Default + Copy,
     {
         Self::check_params()?;
 
@@ -99,8 +104,7 @@ impl<const T: usize, const N: usize> Shamir<T, N> {
             x_coordinates[i] = F::from(identifier as u64);
             y_coordinates[i] = y.unwrap();
         }
-        let secret = Self::interpolate(&x_coordinates, &y_coordinates);
-        Ok(secret)
+        Ok((x_coordinates, y_coordinates))
     }
 
     pub(crate) fn get_shares_and_polynomial<F, R, const S: usize>(
@@ -132,18 +136,29 @@ impl<const T: usize, const N: usize> Shamir<T, N> {
     }
 
     /// Calculate lagrange interpolation
-    fn interpolate<F, S>(x_coordinates: &[F], y_coordinates: &[S]) -> S
+    fn interpolate<F, S>(x_coordinates: &[F; T], y_coordinates: &[S; T]) -> S
     where
         F: PrimeField,
         S: Default + Copy + AddAssign + Mul<F, Output = S>,
     {
-        let limit = x_coordinates.len();
         // Initialize to zero
         let mut result = S::default();
 
-        for i in 0..limit {
-            let mut basis = F::one();
-            for j in 0..limit {
+        for (y, basis) in y_coordinates
+            .iter()
+            .zip(Self::lagrange_basis(x_coordinates))
+        {
+            result += *y * basis;
+        }
+        result
+    }
+
+    /// Calculate the lagrange basis polynomials at zero
+    fn lagrange_basis<F: PrimeField>(x_coordinates: &[F; T]) -> [F; T] {
+        let mut bases = [F::one(); T];
+
+        for (i, basis) in bases.iter_mut().enumerate() {
+            for j in 0..T {
                 if i == j {
                     continue;
                 }
@@ -151,12 +166,10 @@ impl<const T: usize, const N: usize> Shamir<T, N> {
                 let mut denom: F = x_coordinates[j] - x_coordinates[i];
                 denom = denom.invert().unwrap();
                 // x_m / (x_m - x_j) * ...
-                basis *= x_coordinates[j] * denom;
+                *basis *= x_coordinates[j] * denom;
             }
-
-            result += y_coordinates[i] * basis;
         }
-        result
+        bases
     }
 
     pub(crate) fn check_params() -> Result<(), Error> {
@@ -3,7 +3,10 @@
     SPDX-License-Identifier: Apache-2.0
 */
 
+use super::Hex;
+use crate::codec::ByteWriter;
 use crate::error::Error;
+use crate::util::bytes_to_field;
 use core::{
     array::TryFromSliceError,
     convert::TryFrom,
@@ -13,7 +16,7 @@ use elliptic_curve::{ff::PrimeField, group::GroupEncoding};
 use serde::de::Unexpected;
 use serde::{
     de::{self, SeqAccess, Visitor},
-    ser::{self, SerializeSeq},
+    ser::SerializeSeq,
     Deserialize, Deserializer, Serialize, Serializer,
 };
 use zeroize::Zeroize;
@@ -56,12 +59,7 @@ impl<const N: usize> Serialize for Share<N> {
         S: Serializer,
     {
         if s.is_human_readable() {
-            let mut output = [0u8; 66];
-            let len = self.0.len();
-            hex::encode_to_slice(self.0, &mut output[..len * 2])
-                .map_err(|_| ser::Error::custom("invalid length"))?;
-            let h = unsafe { core::str::from_utf8_unchecked(&output[..len * 2]) };
-            s.serialize_str(h)
+            s.collect_str(&Hex(&self.0))
         } else {
             let mut seq = s.serialize_seq(Some(N))?;
             for b in &self.0 {
@@ -119,6 +117,44 @@ impl<'de, const N: usize> Deserialize<'de> for Share<N> {
 }
 
 impl<const N: usize> Share<N> {
+    /// Create a share from an identifier and a prime field element
+    pub fn from_field_element<F: PrimeField>(identifier: u8, value: F) -> Result<Self, Error> {
+        Self::from_parts(identifier, value.to_repr().as_ref())
+    }
+
+    /// Create a share from an identifier and a group element
+    pub fn from_group_element<G: GroupEncoding>(identifier: u8, value: G) -> Result<Self, Error> {
+        Self::from_parts(identifier, value.to_bytes().as_ref())
+    }
+
+    fn from_parts(identifier: u8, value: &[u8]) -> Result<Self, Error> {
+        if identifier == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        if value.len() + 1 != N {
+            return Err(Error::InvalidShareConversion);
+        }
+        let mut bytes = [0u8; N];
+        bytes[0] = identifier;
+        bytes[1..].copy_from_slice(value);
+        Ok(Self(bytes))
+    }
+
+    /// Sum the shares with the same identifier that a participant
+    /// received from several dealers into a single share.
+    pub fn sum_field_elements<F: PrimeField>(shares: &[Self]) -> Result<Self, Error> {
+        let first = shares.first().ok_or(Error::InvalidShare)?;
+        let identifier = first.identifier();
+        let mut value = F::zero();
+        for s in shares {
+            if s.identifier() != identifier {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            value += bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+        }
+        Self::from_field_element(identifier, value)
+    }
+
     /// True if all value bytes are zero in constant time
     pub fn is_zero(&self) -> bool {
         let mut v = 0u8;
@@ -161,4 +197,28 @@ impl<const N: usize> Share<N> {
     pub fn value(&self) -> &[u8] {
         &self.0[1..]
     }
+
+    /// The length of [`Share::to_bytes_into`] which is always `N`
+    pub fn bytes_len(&self) -> usize {
+        N
+    }
+
+    /// Write the canonical encoding, the identifier byte followed by the value
+    /// in the canonical encoding of its field or group element, into `out`
+    /// returning the number of bytes written.
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let mut writer = ByteWriter::new(out);
+        writer.write(&self.0)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a share written with [`Share::to_bytes_into`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if N < 2 || bytes.len() != N || bytes[0] == 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut share = Self::default();
+        share.0.copy_from_slice(bytes);
+        Ok(share)
+    }
 }
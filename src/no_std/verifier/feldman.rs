@@ -4,11 +4,15 @@
 */
 
 use super::super::*;
-use crate::util::bytes_to_field;
+use crate::codec::{verifier_len, ByteReader, ByteWriter};
+use crate::util::{bytes_to_field, sum_of_products};
+use crate::Error as SharingError;
+use core::ops::Add;
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
 };
+use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 
 /// A Feldman verifier is used to provide integrity checking of shamir shares
@@ -35,6 +39,34 @@ pub struct FeldmanVerifier<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F
 impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize>
     FeldmanVerifier<F, G, T>
 {
+    /// Compute the public verification key `g^{f(i)}` of the share
+    /// with `identifier` by evaluating the commitments in the exponent.
+    pub fn public_share(&self, identifier: u8) -> G {
+        let x = F::from(identifier as u64);
+        let mut i = F::one();
+
+        // {1, i, i^2, ..., i^t}
+        let mut powers = [F::default(); T];
+        for p in powers.iter_mut() {
+            *p = i;
+            i *= x;
+        }
+
+        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
+        // the identifier is public so this can be done in variable time
+        sum_of_products(&self.commitments, &powers)
+    }
+
+    /// Compute the public verification keys `g^{f(i)}` for
+    /// identifiers `1..=N`.
+    pub fn public_shares<const N: usize>(&self) -> [G; N] {
+        let mut shares = [G::identity(); N];
+        for (i, s) in shares.iter_mut().enumerate() {
+            *s = self.public_share((i + 1) as u8);
+        }
+        shares
+    }
+
     /// Check whether the share is valid according this verifier set
     pub fn verify<const S: usize>(&self, share: &Share<S>) -> bool {
         let s = bytes_to_field::<F>(share.value());
@@ -43,24 +75,7 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize>
         }
 
         let s = s.unwrap();
-        let x = F::from(share.identifier() as u64);
-        let mut i = F::one();
-
-        // FUTURE: execute this sum of products
-        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
-
-        // c_0
-        let mut rhs = self.commitments[0];
-        for v in &self.commitments[1..] {
-            i *= x;
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i;
-        }
+        let rhs = self.public_share(share.identifier());
 
         let lhs: G = -self.generator * s;
 
@@ -68,4 +83,110 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize>
 
         res.is_identity().unwrap_u8() == 1
     }
+
+    /// Check whether all the shares are valid according to this verifier set
+    /// at once using a random linear combination of the shares.
+    ///
+    /// This costs a single multi-scalar multiplication over the commitments
+    /// instead of one per share. If the batch fails, each share is checked
+    /// individually and the invalid shares are marked with `true`.
+    pub fn verify_batch<R: RngCore + CryptoRng, const S: usize, const M: usize>(
+        &self,
+        shares: &[Share<S>; M],
+        rng: &mut R,
+    ) -> Result<(), [bool; M]> {
+        // Σ r_j * i_j^k for each commitment k
+        let mut scalars = [F::zero(); T];
+        // Σ r_j * s_j
+        let mut sum = F::zero();
+
+        for share in shares {
+            let s = match bytes_to_field::<F>(share.value()) {
+                Some(s) => s,
+                None => return Err(self.find_invalid(shares)),
+            };
+            let r = F::random(&mut *rng);
+            let x = F::from(share.identifier() as u64);
+            let mut p = r;
+            for c in scalars.iter_mut() {
+                *c += p;
+                p *= x;
+            }
+            sum += r * s;
+        }
+
+        let rhs: G = sum_of_products(&self.commitments, &scalars);
+        let lhs: G = -self.generator * sum;
+        let res: G = lhs + rhs;
+
+        if res.is_identity().unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(self.find_invalid(shares))
+        }
+    }
+
+    /// The length of [`FeldmanVerifier::to_bytes_into`]
+    pub fn bytes_len(&self) -> usize {
+        verifier_len::<G>(1, 1, T)
+    }
+
+    /// Write the canonical encoding, the threshold `T` as one byte followed
+    /// by the generator and the `T` commitments in the canonical encoding
+    /// of `G`, into `out` returning the number of bytes written.
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, SharingError> {
+        let mut writer = ByteWriter::new(out);
+        writer.write_count(T)?;
+        writer.write_group(&self.generator)?;
+        writer.write_groups(&self.commitments)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a verifier written with [`FeldmanVerifier::to_bytes_into`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SharingError> {
+        let mut reader = ByteReader::new(bytes);
+        if T < 2 || reader.read_u8()? as usize != T {
+            return Err(SharingError::InvalidEncoding);
+        }
+        let generator = reader.read_group()?;
+        let mut commitments = [G::identity(); T];
+        reader.read_groups(&mut commitments)?;
+        reader.finish()?;
+        Ok(Self {
+            generator,
+            commitments,
+            marker: PhantomData,
+        })
+    }
+
+    fn find_invalid<const S: usize, const M: usize>(&self, shares: &[Share<S>; M]) -> [bool; M] {
+        let mut invalid = [false; M];
+        for (v, s) in invalid.iter_mut().zip(shares.iter()) {
+            *v = !self.verify(s);
+        }
+        invalid
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize> Add
+    for FeldmanVerifier<F, G, T>
+{
+    type Output = Result<Self, SharingError>;
+
+    /// Combine the verifiers of several dealers into the verifier
+    /// for the sum of their shares
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.generator != rhs.generator {
+            return Err(SharingError::InvalidGenerator);
+        }
+        let mut commitments = self.commitments;
+        for (a, b) in commitments.iter_mut().zip(rhs.commitments.iter()) {
+            *a += *b;
+        }
+        Ok(FeldmanVerifier {
+            generator: self.generator,
+            commitments,
+            marker: PhantomData,
+        })
+    }
 }
@@ -5,11 +5,18 @@
 
 use super::super::*;
 use super::FeldmanVerifier;
-use crate::{util::bytes_to_field, Share};
+use crate::{
+    codec::{verifier_len, ByteReader, ByteWriter},
+    util::{bytes_to_field, sum_of_products},
+    Share,
+};
+use crate::{Error as SharingError, HashToGroup};
+use core::ops::Add;
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
 };
+use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 
 /// A Pedersen verifier is used to provide integrity checking of shamir shares
@@ -52,22 +59,17 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize>
         let x = F::from(share.identifier() as u64);
         let mut i = F::one();
 
-        // FUTURE: execute this sum of products
-        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
-
-        // c_0
-        let mut rhs = self.commitments[0];
-        for v in &self.commitments[1..] {
+        // {1, i, i^2, ..., i^t}
+        let mut powers = [F::default(); T];
+        for p in powers.iter_mut() {
+            *p = i;
             i *= x;
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i;
         }
 
+        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
+        // the identifier is public so this can be done in variable time
+        let rhs: G = sum_of_products(&self.commitments, &powers);
+
         let g: G = (-self.feldman_verifier.generator) * secret;
         let h: G = (-self.generator) * blinding;
 
@@ -75,4 +77,144 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize>
 
         res.is_identity().unwrap_u8() == 1
     }
+
+    /// Check whether all the shares and blind shares are valid according to
+    /// this verifier set at once using a random linear combination of the shares.
+    ///
+    /// This costs a single multi-scalar multiplication over the commitments
+    /// instead of one per share. If the batch fails, each share is checked
+    /// individually and the invalid shares are marked with `true`.
+    pub fn verify_batch<R: RngCore + CryptoRng, const S: usize, const M: usize>(
+        &self,
+        shares: &[Share<S>; M],
+        blind_shares: &[Share<S>; M],
+        rng: &mut R,
+    ) -> Result<(), [bool; M]> {
+        // Σ r_j * i_j^k for each commitment k
+        let mut scalars = [F::zero(); T];
+        // Σ r_j * s_j and Σ r_j * b_j
+        let mut secret_sum = F::zero();
+        let mut blinding_sum = F::zero();
+
+        for (share, blind_share) in shares.iter().zip(blind_shares.iter()) {
+            let secret = bytes_to_field::<F>(share.value());
+            let blinding = bytes_to_field::<F>(blind_share.value());
+            if secret.is_none() || blinding.is_none() {
+                return Err(self.find_invalid(shares, blind_shares));
+            }
+            let r = F::random(&mut *rng);
+            let x = F::from(share.identifier() as u64);
+            let mut p = r;
+            for c in scalars.iter_mut() {
+                *c += p;
+                p *= x;
+            }
+            secret_sum += r * secret.unwrap();
+            blinding_sum += r * blinding.unwrap();
+        }
+
+        let rhs: G = sum_of_products(&self.commitments, &scalars);
+        let g: G = (-self.feldman_verifier.generator) * secret_sum;
+        let h: G = (-self.generator) * blinding_sum;
+        let res: G = rhs + g + h;
+
+        if res.is_identity().unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(self.find_invalid(shares, blind_shares))
+        }
+    }
+
+    /// The length of [`PedersenVerifier::to_bytes_into`]
+    pub fn bytes_len(&self) -> usize {
+        verifier_len::<G>(2, 2, T)
+    }
+
+    /// Write the canonical encoding, the threshold `T` as one byte followed
+    /// by the blinding generator, the share generator, the `T` Feldman
+    /// commitments and the `T` blinded commitments in the canonical encoding
+    /// of `G`, into `out` returning the number of bytes written.
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, SharingError> {
+        let mut writer = ByteWriter::new(out);
+        writer.write_count(T)?;
+        writer.write_group(&self.generator)?;
+        writer.write_group(&self.feldman_verifier.generator)?;
+        writer.write_groups(&self.feldman_verifier.commitments)?;
+        writer.write_groups(&self.commitments)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a verifier written with [`PedersenVerifier::to_bytes_into`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SharingError> {
+        let mut reader = ByteReader::new(bytes);
+        if T < 2 || reader.read_u8()? as usize != T {
+            return Err(SharingError::InvalidEncoding);
+        }
+        let generator = reader.read_group()?;
+        let feldman_generator = reader.read_group()?;
+        let mut feldman_commitments = [G::identity(); T];
+        reader.read_groups(&mut feldman_commitments)?;
+        let mut commitments = [G::identity(); T];
+        reader.read_groups(&mut commitments)?;
+        reader.finish()?;
+        Ok(Self {
+            generator,
+            feldman_verifier: FeldmanVerifier {
+                generator: feldman_generator,
+                commitments: feldman_commitments,
+                marker: PhantomData,
+            },
+            commitments,
+        })
+    }
+
+    fn find_invalid<const S: usize, const M: usize>(
+        &self,
+        shares: &[Share<S>; M],
+        blind_shares: &[Share<S>; M],
+    ) -> [bool; M] {
+        let mut invalid = [false; M];
+        for (i, v) in invalid.iter_mut().enumerate() {
+            *v = !self.verify(&shares[i], &blind_shares[i]);
+        }
+        invalid
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F> + HashToGroup, const T: usize>
+    PedersenVerifier<F, G, T>
+{
+    /// Check whether the blinding generator is the nothing-up-my-sleeve
+    /// generator from [`HashToGroup::blinding_generator`].
+    ///
+    /// Pedersen commitments are only binding if the dealer doesn't know the
+    /// discrete log of the blinding generator, so recipients should check
+    /// this before trusting a verifier.
+    pub fn verify_blinding_generator(&self) -> bool {
+        self.generator == G::blinding_generator()
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>, const T: usize> Add
+    for PedersenVerifier<F, G, T>
+{
+    type Output = Result<Self, SharingError>;
+
+    /// Combine the verifiers of several dealers into the verifier
+    /// for the sum of their secret and blind shares
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.generator != rhs.generator {
+            return Err(SharingError::InvalidGenerator);
+        }
+        let feldman_verifier = (self.feldman_verifier + rhs.feldman_verifier)?;
+        let mut commitments = self.commitments;
+        for (a, b) in commitments.iter_mut().zip(rhs.commitments.iter()) {
+            *a += *b;
+        }
+        Ok(PedersenVerifier {
+            generator: self.generator,
+            feldman_verifier,
+            commitments,
+        })
+    }
 }
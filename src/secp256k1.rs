@@ -6,6 +6,7 @@
 //! k256::ProjectivePoint types.
 //! The intent is the consumer will not have to use these directly since
 //! the wrappers implement the [`From`] and [`Into`] traits.
+use crate::{Error, HashToGroup};
 use core::{
     borrow::Borrow,
     fmt,
@@ -17,10 +18,14 @@ use elliptic_curve::{
     ff::{Field, PrimeField},
     generic_array::GenericArray,
     group::{Group, GroupEncoding},
+    hash2curve::{ExpandMsgXmd, GroupDigest},
     ops::Reduce,
     sec1::{FromEncodedPoint, ToEncodedPoint},
 };
-use k256::{AffinePoint, CompressedPoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar};
+use k256::{
+    sha2::Sha256, AffinePoint, CompressedPoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar,
+    Secp256k1,
+};
 use rand_core::RngCore;
 use serde::{
     de::{self, Visitor},
@@ -78,7 +83,7 @@ where
     }
 }
 
-impl<'a> Neg for &'a WrappedProjectivePoint {
+impl Neg for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -102,7 +107,7 @@ impl PartialEq for WrappedProjectivePoint {
     }
 }
 
-impl<'a, 'b> Add<&'b WrappedProjectivePoint> for &'a WrappedProjectivePoint {
+impl<'b> Add<&'b WrappedProjectivePoint> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -120,7 +125,7 @@ impl<'b> Add<&'b WrappedProjectivePoint> for WrappedProjectivePoint {
     }
 }
 
-impl<'a> Add<WrappedProjectivePoint> for &'a WrappedProjectivePoint {
+impl Add<WrappedProjectivePoint> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -152,7 +157,7 @@ impl<'b> AddAssign<&'b WrappedProjectivePoint> for WrappedProjectivePoint {
     }
 }
 
-impl<'a, 'b> Sub<&'b WrappedProjectivePoint> for &'a WrappedProjectivePoint {
+impl<'b> Sub<&'b WrappedProjectivePoint> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -170,7 +175,7 @@ impl<'b> Sub<&'b WrappedProjectivePoint> for WrappedProjectivePoint {
     }
 }
 
-impl<'a> Sub<WrappedProjectivePoint> for &'a WrappedProjectivePoint {
+impl Sub<WrappedProjectivePoint> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -202,7 +207,7 @@ impl<'b> SubAssign<&'b WrappedProjectivePoint> for WrappedProjectivePoint {
     }
 }
 
-impl<'a, 'b> Mul<&'b WrappedScalar> for &'a WrappedProjectivePoint {
+impl<'b> Mul<&'b WrappedScalar> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -220,7 +225,7 @@ impl<'b> Mul<&'b WrappedScalar> for WrappedProjectivePoint {
     }
 }
 
-impl<'a> Mul<WrappedScalar> for &'a WrappedProjectivePoint {
+impl Mul<WrappedScalar> for &WrappedProjectivePoint {
     type Output = WrappedProjectivePoint;
 
     #[inline]
@@ -268,6 +273,21 @@ impl GroupEncoding for WrappedProjectivePoint {
     }
 }
 
+impl HashToGroup for WrappedProjectivePoint {
+    /// secp256k1_XMD:SHA-256_SSWU_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        ProjectivePoint::hash_to_group(msg, dst).map(Self)
+    }
+}
+
+impl HashToGroup for ProjectivePoint {
+    /// secp256k1_XMD:SHA-256_SSWU_RO_
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self, Error> {
+        Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], dst)
+            .map_err(|_| Error::InvalidGenerator)
+    }
+}
+
 impl Default for WrappedProjectivePoint {
     fn default() -> Self {
         Self(ProjectivePoint::IDENTITY)
@@ -443,7 +463,7 @@ impl PartialEq for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Add<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Add<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -461,7 +481,7 @@ impl<'b> Add<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Add<WrappedScalar> for &'a WrappedScalar {
+impl Add<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -493,7 +513,7 @@ impl<'b> AddAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Sub<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Sub<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -511,7 +531,7 @@ impl<'b> Sub<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Sub<WrappedScalar> for &'a WrappedScalar {
+impl Sub<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -543,7 +563,7 @@ impl<'b> SubAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a, 'b> Mul<&'b WrappedScalar> for &'a WrappedScalar {
+impl<'b> Mul<&'b WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -561,7 +581,7 @@ impl<'b> Mul<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Mul<WrappedScalar> for &'a WrappedScalar {
+impl Mul<WrappedScalar> for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -593,7 +613,7 @@ impl<'b> MulAssign<&'b WrappedScalar> for WrappedScalar {
     }
 }
 
-impl<'a> Neg for &'a WrappedScalar {
+impl Neg for &WrappedScalar {
     type Output = WrappedScalar;
 
     #[inline]
@@ -648,7 +668,7 @@ impl<'de> Deserialize<'de> for WrappedScalar {
 fn serde_scalar() {
     use elliptic_curve::ff::Field;
 
-    let rng = rand::rngs::OsRng::default();
+    let rng = rand::rngs::OsRng;
     let ws1 = WrappedScalar::from(Scalar::random(rng));
     // serialize
     let res = serde_bare::to_vec(&ws1);
@@ -665,7 +685,7 @@ fn serde_scalar() {
 fn serde_projective_point() {
     use elliptic_curve::group::Group;
 
-    let rng = rand::rngs::OsRng::default();
+    let rng = rand::rngs::OsRng;
     let wpp1 = WrappedProjectivePoint::from(ProjectivePoint::random(rng));
     // serialize
     let res = serde_bare::to_vec(&wpp1);
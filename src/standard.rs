@@ -1,15 +1,54 @@
+mod additive;
+#[cfg(any(feature = "der", test))]
+mod asn1;
+pub(crate) mod bech32;
+mod bivariate;
+#[cfg(any(feature = "bls12_381", test))]
+mod bls;
+mod checked;
+mod complaint;
+mod dealer;
+mod elgamal;
+mod envelope;
 mod feldman;
+mod frost;
+mod gjkr;
+#[cfg(any(feature = "bls12_381", test))]
+mod kzg;
 mod pedersen;
 mod polynomial;
+mod pvss;
+mod ramp;
 mod shamir;
 mod share;
+mod sigma;
+mod transport;
 mod verifier;
 
+pub use additive::*;
+#[cfg(any(feature = "der", test))]
+pub use asn1::*;
+pub use bivariate::*;
+#[cfg(any(feature = "bls12_381", test))]
+pub use bls::*;
+pub use checked::*;
+pub use complaint::*;
+pub use dealer::*;
+pub use elgamal::*;
+pub use envelope::*;
 pub use feldman::*;
+pub use frost::*;
+pub use gjkr::*;
+#[cfg(any(feature = "bls12_381", test))]
+pub use kzg::*;
 pub use pedersen::*;
 pub use polynomial::*;
+pub use pvss::*;
+pub use ramp::*;
 pub use shamir::*;
 pub use share::*;
+pub use sigma::*;
+pub use transport::*;
 pub use verifier::*;
 
 use crate::lib::{String, Vec};
@@ -87,6 +126,112 @@ pub(crate) fn deserialize_scalar<'de, F: PrimeField, D: Deserializer<'de>>(
     }
 }
 
+pub(crate) fn serialize_scalar_vec<F: PrimeField, S: Serializer>(
+    scalars: &Vec<F>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    struct ScalarRef<'a, F: PrimeField>(&'a F);
+
+    impl<F: PrimeField> serde::Serialize for ScalarRef<'_, F> {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            serialize_scalar(self.0, s)
+        }
+    }
+
+    let mut sequencer = s.serialize_seq(Some(scalars.len()))?;
+    for scalar in scalars {
+        sequencer.serialize_element(&ScalarRef(scalar))?;
+    }
+    sequencer.end()
+}
+
+pub(crate) fn deserialize_scalar_vec<'de, F: PrimeField, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Vec<F>, D::Error> {
+    struct ScalarWrapper<F: PrimeField>(F);
+
+    impl<'de, F: PrimeField> serde::Deserialize<'de> for ScalarWrapper<F> {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            deserialize_scalar(d).map(Self)
+        }
+    }
+
+    struct ScalarVecVisitor<F: PrimeField> {
+        marker: PhantomData<F>,
+    }
+
+    impl<'de, F: PrimeField> Visitor<'de> for ScalarVecVisitor<F> {
+        type Value = Vec<F>;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "a sequence of scalars")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut values = Vec::new();
+            while let Some(ScalarWrapper(f)) = seq.next_element()? {
+                values.push(f);
+            }
+            Ok(values)
+        }
+    }
+
+    d.deserialize_seq(ScalarVecVisitor {
+        marker: PhantomData::<F>,
+    })
+}
+
+pub(crate) fn serialize_byte_vec<S: Serializer>(bytes: &Vec<u8>, s: S) -> Result<S::Ok, S::Error> {
+    if s.is_human_readable() {
+        s.serialize_str(&hex::encode(bytes))
+    } else {
+        let mut sequencer = s.serialize_seq(Some(bytes.len()))?;
+        for b in bytes {
+            sequencer.serialize_element(b)?;
+        }
+        sequencer.end()
+    }
+}
+
+pub(crate) fn deserialize_byte_vec<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+    struct ByteVecVisitor;
+
+    impl<'de> Visitor<'de> for ByteVecVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "a hex string or byte sequence")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            hex::decode(v).map_err(|_| Error::invalid_value(Unexpected::Str(v), &self))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::new();
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+
+    if d.is_human_readable() {
+        d.deserialize_str(ByteVecVisitor)
+    } else {
+        d.deserialize_seq(ByteVecVisitor)
+    }
+}
+
 pub(crate) fn serialize_group<G: Group + GroupEncoding, S: Serializer>(
     g: &G,
     s: S,
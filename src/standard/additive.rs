@@ -0,0 +1,137 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use super::{Feldman, FeldmanVerifier, Share};
+use crate::lib::*;
+use crate::util::{bytes_to_field, lagrange};
+use crate::Error;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+
+/// Additive (n-of-n) secret sharing scheme.
+///
+/// The secret is the sum of all the shares so every share is
+/// required to reconstruct it. Most MPC protocols operate on additive
+/// shares, so this also provides conversions to and from Shamir shares.
+///
+/// To convert Shamir shares, each signer in a subset calls `from_shamir_share`
+/// with the identifiers in the subset. The resulting shares are additive shares
+/// of the same secret among that subset.
+///
+/// To convert back, each party calls `to_shamir` to reshare its additive share
+/// using Feldman, sends the results to the other parties who then
+/// call `combine_reshares` with everything they received.
+#[derive(Copy, Clone, Debug)]
+pub struct Additive {
+    /// The number of shares to allocate
+    pub n: usize,
+}
+
+impl Additive {
+    /// Create shares from a secret.
+    /// F is the prime field
+    pub fn split_secret<F, R>(&self, secret: F, rng: &mut R) -> Result<Vec<Share>, Error>
+    where
+        F: PrimeField,
+        R: RngCore + CryptoRng,
+    {
+        self.check_params()?;
+
+        let mut shares = Vec::with_capacity(self.n);
+        let mut sum = F::zero();
+        for i in 1..self.n {
+            let value = F::random(&mut *rng);
+            sum += value;
+            shares.push(Share::from_field_element(i as u8, value)?);
+        }
+        shares.push(Share::from_field_element(self.n as u8, secret - sum)?);
+        Ok(shares)
+    }
+
+    /// Reconstruct a secret from all the shares created from `split_secret`.
+    pub fn combine_shares<F>(&self, shares: &[Share]) -> Result<F, Error>
+    where
+        F: PrimeField,
+    {
+        self.check_params()?;
+
+        if shares.len() != self.n {
+            return Err(Error::SharingMinThreshold);
+        }
+
+        let mut dups = BTreeSet::new();
+        let mut secret = F::zero();
+        for s in shares {
+            let identifier = s.identifier();
+            if identifier == 0 {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if !dups.insert(identifier) {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            secret += bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+        }
+        Ok(secret)
+    }
+
+    /// Convert a Shamir share into an additive share by multiplying
+    /// it with its lagrange coefficient for the `participants`.
+    ///
+    /// The additive shares of all `participants` sum to the Shamir secret.
+    pub fn from_shamir_share<F>(share: &Share, participants: &[u8]) -> Result<Share, Error>
+    where
+        F: PrimeField,
+    {
+        let value = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare)?;
+        let coefficient = lagrange::<F>(share.identifier(), participants)?;
+        Share::from_field_element(share.identifier(), value * coefficient)
+    }
+
+    /// Reshare an additive share with Feldman to begin converting it
+    /// back into a `threshold` of `limit` Shamir share.
+    ///
+    /// Each resulting share and the verifier are sent to the party
+    /// with the same identifier.
+    pub fn to_shamir<F, G, R>(
+        share: &Share,
+        threshold: usize,
+        limit: usize,
+        generator: Option<G>,
+        rng: &mut R,
+    ) -> Result<(Vec<Share>, FeldmanVerifier<F, G>), Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + Default + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let value = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare)?;
+        Feldman {
+            t: threshold,
+            n: limit,
+        }
+        .split_secret(value, generator, rng)
+    }
+
+    /// Complete the conversion to Shamir by summing the reshares
+    /// this party received from every additive share holder.
+    pub fn combine_reshares<F>(shares: &[Share]) -> Result<Share, Error>
+    where
+        F: PrimeField,
+    {
+        Share::sum_field_elements::<F>(shares)
+    }
+
+    pub(crate) fn check_params(&self) -> Result<(), Error> {
+        if self.n < 2 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if self.n > 255 {
+            return Err(Error::SharingMaxRequest);
+        }
+        Ok(())
+    }
+}
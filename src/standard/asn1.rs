@@ -0,0 +1,382 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{FeldmanVerifier, PedersenVerifier, Share};
+use crate::lib::*;
+use crate::util::bytes_to_group;
+use crate::{CurveId, CurveIdentifier, Error};
+use core::marker::PhantomData;
+use der::{
+    asn1::{AnyRef, OctetStringRef},
+    pem::{self, LineEnding},
+    Decode, Encode, Reader, Sequence, Tag, Tagged,
+};
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+
+const DER_VERSION: u8 = 1;
+
+/// The PEM label of a [`Share`]
+pub const SHARE_PEM_LABEL: &str = "VSSS SHARE";
+/// The PEM label of a [`FeldmanVerifier`]
+pub const FELDMAN_VERIFIER_PEM_LABEL: &str = "VSSS FELDMAN VERIFIER";
+/// The PEM label of a [`PedersenVerifier`]
+pub const PEDERSEN_VERIFIER_PEM_LABEL: &str = "VSSS PEDERSEN VERIFIER";
+
+impl Share {
+    /// Encode this share as DER for the curve of `F` with the ASN.1 structure
+    ///
+    /// ```text
+    /// VsssShare ::= SEQUENCE {
+    ///     version     INTEGER { v1(1) },
+    ///     curve       OBJECT IDENTIFIER,
+    ///     identifier  INTEGER (1..255),
+    ///     value       OCTET STRING
+    /// }
+    /// ```
+    ///
+    /// where `curve` is [`CurveId::oid`] and `value` is the canonical
+    /// encoding of the field element.
+    pub fn to_der<F: PrimeField + CurveIdentifier>(&self) -> Result<Vec<u8>, Error> {
+        let curve = oid_content(F::CURVE_ID);
+        ShareInfo {
+            curve: oid_ref(&curve)?,
+            identifier: self.identifier(),
+            value: octets(&self.value())?,
+        }
+        .to_vec()
+        .map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Decode a DER `VsssShare` for the curve of `F`
+    pub fn from_der<F: PrimeField + CurveIdentifier>(bytes: &[u8]) -> Result<Self, Error> {
+        let info = ShareInfo::from_der(bytes).map_err(|_| Error::InvalidEncoding)?;
+        check_curve(info.curve, F::CURVE_ID)?;
+        let value = info.value.as_bytes();
+        if info.identifier == 0 || value.len() != F::Repr::default().as_ref().len() {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut share = Share(Vec::with_capacity(value.len() + 1));
+        share.0.push(info.identifier);
+        share.0.extend_from_slice(value);
+        share
+            .as_field_element::<F>()
+            .map_err(|_| Error::InvalidEncoding)?;
+        Ok(share)
+    }
+
+    /// Encode this share as PEM with the label `VSSS SHARE`
+    pub fn to_pem<F: PrimeField + CurveIdentifier>(&self) -> Result<String, Error> {
+        to_pem(SHARE_PEM_LABEL, &self.to_der::<F>()?)
+    }
+
+    /// Decode a PEM `VSSS SHARE`
+    pub fn from_pem<F: PrimeField + CurveIdentifier>(s: &str) -> Result<Self, Error> {
+        Self::from_der::<F>(&from_pem(SHARE_PEM_LABEL, s)?)
+    }
+}
+
+impl<F, G> FeldmanVerifier<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F> + CurveIdentifier,
+{
+    /// Encode this verifier as DER with the ASN.1 structure
+    ///
+    /// ```text
+    /// VsssFeldmanVerifier ::= SEQUENCE {
+    ///     version     INTEGER { v1(1) },
+    ///     curve       OBJECT IDENTIFIER,
+    ///     generator   OCTET STRING,
+    ///     commitments SEQUENCE OF OCTET STRING
+    /// }
+    /// ```
+    ///
+    /// where `curve` is [`CurveId::oid`] and the group elements are
+    /// in their canonical encoding.
+    pub fn to_der(&self) -> Result<Vec<u8>, Error> {
+        let curve = oid_content(G::CURVE_ID);
+        let generator = self.generator.to_bytes();
+        let commitments = to_reprs(&self.commitments);
+        FeldmanInfo {
+            curve: oid_ref(&curve)?,
+            generator: octets(&generator)?,
+            commitments: octets_vec(&commitments)?,
+        }
+        .to_vec()
+        .map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Decode a DER `VsssFeldmanVerifier`
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let info = FeldmanInfo::from_der(bytes).map_err(|_| Error::InvalidEncoding)?;
+        check_curve(info.curve, G::CURVE_ID)?;
+        let commitments = groups(&info.commitments)?;
+        if commitments.len() < 2 {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Self {
+            generator: group(info.generator)?,
+            commitments,
+            marker: PhantomData,
+        })
+    }
+
+    /// Encode this verifier as PEM with the label `VSSS FELDMAN VERIFIER`
+    pub fn to_pem(&self) -> Result<String, Error> {
+        to_pem(FELDMAN_VERIFIER_PEM_LABEL, &self.to_der()?)
+    }
+
+    /// Decode a PEM `VSSS FELDMAN VERIFIER`
+    pub fn from_pem(s: &str) -> Result<Self, Error> {
+        Self::from_der(&from_pem(FELDMAN_VERIFIER_PEM_LABEL, s)?)
+    }
+}
+
+impl<F, G> PedersenVerifier<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F> + CurveIdentifier,
+{
+    /// Encode this verifier as DER with the ASN.1 structure
+    ///
+    /// ```text
+    /// VsssPedersenVerifier ::= SEQUENCE {
+    ///     version            INTEGER { v1(1) },
+    ///     curve              OBJECT IDENTIFIER,
+    ///     blindingGenerator  OCTET STRING,
+    ///     generator          OCTET STRING,
+    ///     commitments        SEQUENCE OF OCTET STRING,
+    ///     blindCommitments   SEQUENCE OF OCTET STRING
+    /// }
+    /// ```
+    ///
+    /// where `curve` is [`CurveId::oid`], `generator` and `commitments` are
+    /// the Feldman verifier and the group elements are in their canonical encoding.
+    pub fn to_der(&self) -> Result<Vec<u8>, Error> {
+        let curve = oid_content(G::CURVE_ID);
+        let blinding_generator = self.generator.to_bytes();
+        let generator = self.feldman_verifier.generator.to_bytes();
+        let commitments = to_reprs(&self.feldman_verifier.commitments);
+        let blind_commitments = to_reprs(&self.commitments);
+        PedersenInfo {
+            curve: oid_ref(&curve)?,
+            blinding_generator: octets(&blinding_generator)?,
+            generator: octets(&generator)?,
+            commitments: octets_vec(&commitments)?,
+            blind_commitments: octets_vec(&blind_commitments)?,
+        }
+        .to_vec()
+        .map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Decode a DER `VsssPedersenVerifier`
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let info = PedersenInfo::from_der(bytes).map_err(|_| Error::InvalidEncoding)?;
+        check_curve(info.curve, G::CURVE_ID)?;
+        let commitments = groups(&info.commitments)?;
+        let blind_commitments = groups(&info.blind_commitments)?;
+        if commitments.len() < 2 || commitments.len() != blind_commitments.len() {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Self {
+            generator: group(info.blinding_generator)?,
+            feldman_verifier: FeldmanVerifier {
+                generator: group(info.generator)?,
+                commitments,
+                marker: PhantomData,
+            },
+            commitments: blind_commitments,
+        })
+    }
+
+    /// Encode this verifier as PEM with the label `VSSS PEDERSEN VERIFIER`
+    pub fn to_pem(&self) -> Result<String, Error> {
+        to_pem(PEDERSEN_VERIFIER_PEM_LABEL, &self.to_der()?)
+    }
+
+    /// Decode a PEM `VSSS PEDERSEN VERIFIER`
+    pub fn from_pem(s: &str) -> Result<Self, Error> {
+        Self::from_der(&from_pem(PEDERSEN_VERIFIER_PEM_LABEL, s)?)
+    }
+}
+
+struct ShareInfo<'a> {
+    curve: AnyRef<'a>,
+    identifier: u8,
+    value: OctetStringRef<'a>,
+}
+
+impl<'a> Decode<'a> for ShareInfo<'a> {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> der::Result<Self> {
+        reader.sequence(|r| {
+            check_version(r.decode()?)?;
+            Ok(Self {
+                curve: r.decode()?,
+                identifier: r.decode()?,
+                value: r.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for ShareInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&DER_VERSION, &self.curve, &self.identifier, &self.value])
+    }
+}
+
+struct FeldmanInfo<'a> {
+    curve: AnyRef<'a>,
+    generator: OctetStringRef<'a>,
+    commitments: Vec<OctetStringRef<'a>>,
+}
+
+impl<'a> Decode<'a> for FeldmanInfo<'a> {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> der::Result<Self> {
+        reader.sequence(|r| {
+            check_version(r.decode()?)?;
+            Ok(Self {
+                curve: r.decode()?,
+                generator: r.decode()?,
+                commitments: r.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for FeldmanInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &DER_VERSION,
+            &self.curve,
+            &self.generator,
+            &self.commitments,
+        ])
+    }
+}
+
+struct PedersenInfo<'a> {
+    curve: AnyRef<'a>,
+    blinding_generator: OctetStringRef<'a>,
+    generator: OctetStringRef<'a>,
+    commitments: Vec<OctetStringRef<'a>>,
+    blind_commitments: Vec<OctetStringRef<'a>>,
+}
+
+impl<'a> Decode<'a> for PedersenInfo<'a> {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> der::Result<Self> {
+        reader.sequence(|r| {
+            check_version(r.decode()?)?;
+            Ok(Self {
+                curve: r.decode()?,
+                blinding_generator: r.decode()?,
+                generator: r.decode()?,
+                commitments: r.decode()?,
+                blind_commitments: r.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PedersenInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &DER_VERSION,
+            &self.curve,
+            &self.blinding_generator,
+            &self.generator,
+            &self.commitments,
+            &self.blind_commitments,
+        ])
+    }
+}
+
+fn check_version(version: u8) -> der::Result<()> {
+    if version == DER_VERSION {
+        Ok(())
+    } else {
+        Err(Tag::Integer.value_error())
+    }
+}
+
+/// The content octets of the object identifier of `curve`
+fn oid_content(curve: CurveId) -> Vec<u8> {
+    let arcs = curve.oid();
+    let mut content = Vec::new();
+    push_base128(&mut content, arcs[0] * 40 + arcs[1]);
+    for arc in &arcs[2..] {
+        push_base128(&mut content, *arc);
+    }
+    content
+}
+
+fn push_base128(out: &mut Vec<u8>, mut arc: u128) {
+    let start = out.len();
+    out.push((arc & 0x7f) as u8);
+    arc >>= 7;
+    while arc > 0 {
+        out.insert(start, (arc & 0x7f) as u8 | 0x80);
+        arc >>= 7;
+    }
+}
+
+fn oid_ref(content: &[u8]) -> Result<AnyRef<'_>, Error> {
+    AnyRef::new(Tag::ObjectIdentifier, content).map_err(|_| Error::InvalidEncoding)
+}
+
+fn check_curve(curve: AnyRef<'_>, expected: CurveId) -> Result<(), Error> {
+    if curve.tag() == Tag::ObjectIdentifier && curve.value() == oid_content(expected) {
+        Ok(())
+    } else {
+        Err(Error::InvalidEncoding)
+    }
+}
+
+fn to_reprs<G: GroupEncoding>(groups: &[G]) -> Vec<G::Repr> {
+    groups.iter().map(|g| g.to_bytes()).collect()
+}
+
+fn octets<B: AsRef<[u8]>>(bytes: &B) -> Result<OctetStringRef<'_>, Error> {
+    OctetStringRef::new(bytes.as_ref()).map_err(|_| Error::InvalidEncoding)
+}
+
+fn octets_vec<B: AsRef<[u8]>>(bytes: &[B]) -> Result<Vec<OctetStringRef<'_>>, Error> {
+    bytes.iter().map(octets).collect()
+}
+
+fn group<G: Group + GroupEncoding>(octets: OctetStringRef<'_>) -> Result<G, Error> {
+    let bytes = octets.as_bytes();
+    if bytes.len() != G::Repr::default().as_ref().len() {
+        return Err(Error::InvalidEncoding);
+    }
+    bytes_to_group(bytes).ok_or(Error::InvalidEncoding)
+}
+
+fn groups<G: Group + GroupEncoding>(octets: &[OctetStringRef<'_>]) -> Result<Vec<G>, Error> {
+    octets.iter().map(|o| group(*o)).collect()
+}
+
+fn to_pem(label: &str, der: &[u8]) -> Result<String, Error> {
+    pem::encode_string(label, LineEnding::LF, der).map_err(|_| Error::InvalidEncoding)
+}
+
+fn from_pem(label: &str, s: &str) -> Result<Vec<u8>, Error> {
+    match pem::decode_vec(s.as_bytes()) {
+        Ok((l, der)) if l == label => Ok(der),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
@@ -0,0 +1,277 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{FeldmanVerifier, PedersenVerifier, Share};
+use crate::lib::*;
+use crate::util::bytes_to_group;
+use crate::{CurveId, CurveIdentifier, Error};
+use core::marker::PhantomData;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+
+const SHARE_PREFIX: &str = "vsss";
+const FELDMAN_VERIFIER_PREFIX: &str = "vsssfv";
+const PEDERSEN_VERIFIER_PREFIX: &str = "vssspv";
+
+const SEPARATOR: char = '1';
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LENGTH: usize = 6;
+const MAX_HRP_LENGTH: usize = 83;
+/// The bech32m checksum constant from BIP-350
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const GENERATORS: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+impl Share {
+    /// Encode this share as bech32m with the prefix `vsss` followed by the
+    /// code of `F`, for example `vsssk1...` for a secp256k1 share.
+    ///
+    /// The checksum catches transcription errors so this form is meant for
+    /// writing down or reading aloud. Uppercase is accepted when decoding.
+    pub fn to_bech32<F: PrimeField + CurveIdentifier>(&self) -> String {
+        encode(&prefix(SHARE_PREFIX, F::CURVE_ID), &self.0)
+    }
+
+    /// Decode a share written with [`Share::to_bech32`]
+    pub fn from_bech32<F: PrimeField + CurveIdentifier>(s: &str) -> Result<Self, Error> {
+        let bytes = decode(&prefix(SHARE_PREFIX, F::CURVE_ID), s)?;
+        let share = Share(bytes);
+        if share.0.len() != F::Repr::default().as_ref().len() + 1 || share.identifier() == 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        share
+            .as_field_element::<F>()
+            .map_err(|_| Error::InvalidEncoding)?;
+        Ok(share)
+    }
+}
+
+impl<F, G> FeldmanVerifier<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F> + CurveIdentifier,
+{
+    /// Encode this verifier as bech32m with the prefix `vsssfv` followed by
+    /// the code of `G`. The data is the generator then the commitments.
+    pub fn to_bech32(&self) -> String {
+        let mut bytes = Vec::new();
+        write_groups(&mut bytes, core::iter::once(&self.generator));
+        write_groups(&mut bytes, self.commitments.iter());
+        encode(&prefix(FELDMAN_VERIFIER_PREFIX, G::CURVE_ID), &bytes)
+    }
+
+    /// Decode a verifier written with [`FeldmanVerifier::to_bech32`]
+    pub fn from_bech32(s: &str) -> Result<Self, Error> {
+        let bytes = decode(&prefix(FELDMAN_VERIFIER_PREFIX, G::CURVE_ID), s)?;
+        let mut groups = read_groups::<G>(&bytes)?;
+        if groups.len() < 3 {
+            return Err(Error::InvalidEncoding);
+        }
+        let generator = groups.remove(0);
+        Ok(Self {
+            generator,
+            commitments: groups,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<F, G> PedersenVerifier<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F> + CurveIdentifier,
+{
+    /// Encode this verifier as bech32m with the prefix `vssspv` followed by
+    /// the code of `G`. The data is the blinding generator, the share
+    /// generator, the Feldman commitments then the blinded commitments.
+    pub fn to_bech32(&self) -> String {
+        let mut bytes = Vec::new();
+        write_groups(
+            &mut bytes,
+            [&self.generator, &self.feldman_verifier.generator].into_iter(),
+        );
+        write_groups(&mut bytes, self.feldman_verifier.commitments.iter());
+        write_groups(&mut bytes, self.commitments.iter());
+        encode(&prefix(PEDERSEN_VERIFIER_PREFIX, G::CURVE_ID), &bytes)
+    }
+
+    /// Decode a verifier written with [`PedersenVerifier::to_bech32`]
+    pub fn from_bech32(s: &str) -> Result<Self, Error> {
+        let bytes = decode(&prefix(PEDERSEN_VERIFIER_PREFIX, G::CURVE_ID), s)?;
+        let mut groups = read_groups::<G>(&bytes)?;
+        if groups.len() < 6 || groups.len() % 2 != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        let t = (groups.len() - 2) / 2;
+        let commitments = groups.split_off(2 + t);
+        let feldman_commitments = groups.split_off(2);
+        Ok(Self {
+            generator: groups[0],
+            feldman_verifier: FeldmanVerifier {
+                generator: groups[1],
+                commitments: feldman_commitments,
+                marker: PhantomData,
+            },
+            commitments,
+        })
+    }
+}
+
+/// The short code of each curve used in the human-readable prefix.
+/// Digits are avoided since `1` separates the prefix from the data.
+fn curve_code(curve: CurveId) -> &'static str {
+    match curve {
+        CurveId::Secp256k1 => "k",
+        CurveId::P256 => "p",
+        CurveId::Curve25519 => "c",
+        CurveId::Ed25519 => "e",
+        CurveId::Ristretto25519 => "r",
+        CurveId::Bls12381 => "b",
+        CurveId::Bls12381G1 => "bgone",
+        CurveId::Bls12381G2 => "bgtwo",
+    }
+}
+
+fn prefix(kind: &str, curve: CurveId) -> String {
+    let mut prefix = String::from(kind);
+    prefix.push_str(curve_code(curve));
+    prefix
+}
+
+fn write_groups<'a, G: GroupEncoding + 'a>(
+    bytes: &mut Vec<u8>,
+    groups: impl Iterator<Item = &'a G>,
+) {
+    for g in groups {
+        bytes.extend_from_slice(g.to_bytes().as_ref());
+    }
+}
+
+fn read_groups<G: Group + GroupEncoding>(bytes: &[u8]) -> Result<Vec<G>, Error> {
+    let size = G::Repr::default().as_ref().len();
+    if bytes.is_empty() || bytes.len() % size != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+    bytes
+        .chunks(size)
+        .map(|c| bytes_to_group::<G>(c).ok_or(Error::InvalidEncoding))
+        .collect()
+}
+
+/// Encode `data` as bech32m with `hrp` as described in BIP-350.
+///
+/// Unlike BIP-173 there is no 90 character limit since verifiers are longer.
+/// The checksum still detects any error affecting up to 4 characters in
+/// strings under 90 characters and other errors with probability `1 - 2^-30`.
+pub(crate) fn encode(hrp: &str, data: &[u8]) -> String {
+    let values = convert_bits(data, 8, 5, true).expect("padding is allowed");
+    let checksum = create_checksum(hrp, &values);
+    let mut s = String::with_capacity(hrp.len() + 1 + values.len() + CHECKSUM_LENGTH);
+    s.push_str(hrp);
+    s.push(SEPARATOR);
+    for v in values.iter().chain(checksum.iter()) {
+        s.push(CHARSET[*v as usize] as char);
+    }
+    s
+}
+
+/// Decode a bech32m string and check its prefix is `hrp`
+pub(crate) fn decode(hrp: &str, s: &str) -> Result<Vec<u8>, Error> {
+    if hrp.is_empty() || hrp.len() > MAX_HRP_LENGTH || hrp.bytes().any(|b| !(33..=126).contains(&b))
+    {
+        return Err(Error::InvalidEncoding);
+    }
+    let has_lower = s.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = s.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(Error::InvalidEncoding);
+    }
+    let s = s.to_ascii_lowercase();
+    let pos = s.rfind(SEPARATOR).ok_or(Error::InvalidEncoding)?;
+    let (prefix, rest) = (&s[..pos], &s[pos + 1..]);
+    if prefix != hrp || rest.len() < CHECKSUM_LENGTH {
+        return Err(Error::InvalidEncoding);
+    }
+    let values = rest
+        .bytes()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|x| *x == c)
+                .map(|p| p as u8)
+                .ok_or(Error::InvalidEncoding)
+        })
+        .collect::<Result<Vec<u8>, Error>>()?;
+    if polymod(&checksum_input(hrp, &values)) != BECH32M_CONST {
+        return Err(Error::InvalidEncoding);
+    }
+    convert_bits(&values[..values.len() - CHECKSUM_LENGTH], 5, 8, false)
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk = 1u32;
+    for v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ff_ffff) << 5) ^ (*v as u32);
+        for (i, g) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// The expanded prefix followed by `values`
+fn checksum_input(hrp: &str, values: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(hrp.len() * 2 + 1 + values.len() + CHECKSUM_LENGTH);
+    input.extend(hrp.bytes().map(|b| b >> 5));
+    input.push(0);
+    input.extend(hrp.bytes().map(|b| b & 31));
+    input.extend_from_slice(values);
+    input
+}
+
+fn create_checksum(hrp: &str, values: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let mut input = checksum_input(hrp, values);
+    input.extend_from_slice(&[0u8; CHECKSUM_LENGTH]);
+    let m = polymod(&input) ^ BECH32M_CONST;
+    let mut checksum = [0u8; CHECKSUM_LENGTH];
+    for (i, c) in checksum.iter_mut().enumerate() {
+        *c = ((m >> (5 * (5 - i))) & 31) as u8;
+    }
+    checksum
+}
+
+/// Regroup `data` from `from` bit to `to` bit values
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Error> {
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for v in data {
+        acc = (acc << from) | (*v as u32);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(out)
+}
@@ -0,0 +1,238 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use super::{
+    deserialize_scalar, deserialize_scalar_vec, serialize_scalar, serialize_scalar_vec, Polynomial,
+    Ramp, Shamir, Share,
+};
+use crate::lib::*;
+use crate::Error;
+use elliptic_curve::ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Bivariate polynomial verifiable secret sharing.
+///
+/// The dealer picks a random symmetric `F(x, y)` of degree `t - 1` in each
+/// variable with `F(0, 0) = secret` and sends participant `i` the univariate
+/// polynomial `f_i(y) = F(i, y)`. Since `f_i(j) = f_j(i)` participants
+/// cross-check their pairwise points without any commitments, so security is
+/// information theoretic rather than resting on the discrete log assumption.
+///
+/// `f_i(0)` is a Shamir share of the secret with identifier `i`.
+#[derive(Copy, Clone, Debug)]
+pub struct Bivariate {
+    /// The threshold necessary for combine
+    pub t: usize,
+    /// The number of shares to allocate
+    pub n: usize,
+}
+
+/// A participant's univariate polynomial `f_i(y) = F(i, y)`.
+/// This must be sent over a private channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BivariateShare<F: PrimeField> {
+    /// The participant's identifier `i`
+    pub identifier: u8,
+    /// The coefficients of `f_i` starting with the constant term
+    #[serde(
+        serialize_with = "serialize_scalar_vec",
+        deserialize_with = "deserialize_scalar_vec"
+    )]
+    pub coefficients: Vec<F>,
+}
+
+/// The point `f_i(j)` participant `i` sends privately to participant `j`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BivariatePoint<F: PrimeField> {
+    /// The sender's identifier `i`
+    pub sender: u8,
+    /// The receiver's identifier `j`
+    pub receiver: u8,
+    /// The value `f_i(j)`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub value: F,
+}
+
+impl Bivariate {
+    /// Create a univariate polynomial for each participant from a secret
+    pub fn split_secret<F, R>(
+        &self,
+        secret: F,
+        rng: &mut R,
+    ) -> Result<Vec<BivariateShare<F>>, Error>
+    where
+        F: PrimeField,
+        R: RngCore + CryptoRng,
+    {
+        self.shamir().check_params()?;
+
+        // Symmetric coefficients a_{kl} = a_{lk} with a_{00} = secret
+        let mut a: Vec<Vec<F>> = Vec::with_capacity(self.t);
+        for k in 0..self.t {
+            let mut row = Vec::with_capacity(self.t);
+            row.extend(a.iter().map(|r| r[k]));
+            for l in k..self.t {
+                row.push(if k == 0 && l == 0 {
+                    secret
+                } else {
+                    F::random(&mut *rng)
+                });
+            }
+            a.push(row);
+        }
+
+        // The l-th coefficient of f_i is Σ_k a_{kl} i^k
+        let mut shares = Vec::with_capacity(self.n);
+        let mut x = F::one();
+        for i in 1..=self.n {
+            let coefficients = (0..self.t)
+                .map(|l| {
+                    Polynomial {
+                        coefficients: a.iter().map(|row| row[l]).collect(),
+                    }
+                    .evaluate(x, self.t)
+                })
+                .collect();
+            shares.push(BivariateShare {
+                identifier: i as u8,
+                coefficients,
+            });
+            x += F::one();
+        }
+        Ok(shares)
+    }
+
+    /// Check `share` has degree less than the threshold.
+    ///
+    /// A dealer using a symmetric `F(x, y)` of higher degree passes every
+    /// pairwise check but different subsets reconstruct different secrets.
+    pub fn verify_share<F: PrimeField>(&self, share: &BivariateShare<F>) -> bool {
+        share.identifier != 0 && share.coefficients.len() == self.t
+    }
+
+    /// Check `own` has degree less than the threshold and agrees with a
+    /// point from another participant, i.e. `f_j(i) == f_i(j)`
+    pub fn verify_point<F: PrimeField>(
+        &self,
+        own: &BivariateShare<F>,
+        point: &BivariatePoint<F>,
+    ) -> bool {
+        self.verify_share(own) && own.verify_point(point)
+    }
+
+    /// Reconstruct the secret from at least threshold participants' polynomials
+    pub fn combine_shares<F: PrimeField>(&self, shares: &[BivariateShare<F>]) -> Result<F, Error> {
+        if !shares.iter().all(|s| self.verify_share(s)) {
+            return Err(Error::InvalidShare);
+        }
+        let shares = shares
+            .iter()
+            .map(|s| s.share())
+            .collect::<Result<Vec<Share>, Error>>()?;
+        self.shamir().combine_shares::<F>(&shares)
+    }
+
+    /// Recover participant `identifier`'s polynomial from the points
+    /// `f_j(identifier)` sent by at least threshold other participants.
+    ///
+    /// Returns [`Error::InvalidShare`] if the points beyond the threshold
+    /// don't lie on the recovered polynomial.
+    pub fn recover_share<F: PrimeField>(
+        &self,
+        identifier: u8,
+        points: &[BivariatePoint<F>],
+    ) -> Result<BivariateShare<F>, Error> {
+        self.shamir().check_params()?;
+        if identifier == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        if points.len() < self.t {
+            return Err(Error::SharingMinThreshold);
+        }
+
+        let mut dups = BTreeSet::new();
+        for p in points {
+            if p.receiver != identifier || p.sender == 0 {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if !dups.insert(p.sender) {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+        }
+        // f_j(i) = f_i(j)
+        let x_coordinates: Vec<F> = points[..self.t]
+            .iter()
+            .map(|p| F::from(p.sender as u64))
+            .collect();
+        let y_coordinates: Vec<F> = points[..self.t].iter().map(|p| p.value).collect();
+        let share = BivariateShare {
+            identifier,
+            coefficients: Ramp::interpolate(&x_coordinates, &y_coordinates),
+        };
+        if !points[self.t..]
+            .iter()
+            .all(|p| self.verify_point(&share, p))
+        {
+            return Err(Error::InvalidShare);
+        }
+        Ok(share)
+    }
+
+    fn shamir(&self) -> Shamir {
+        Shamir {
+            t: self.t,
+            n: self.n,
+        }
+    }
+}
+
+impl<F: PrimeField> BivariateShare<F> {
+    /// The Shamir share `f_i(0)` of the secret
+    pub fn share(&self) -> Result<Share, Error> {
+        if self.identifier == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        Share::from_field_element(self.identifier, self.evaluate(0)?)
+    }
+
+    /// The point `f_i(receiver)` to send to `receiver`
+    pub fn point(&self, receiver: u8) -> Result<BivariatePoint<F>, Error> {
+        if receiver == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        Ok(BivariatePoint {
+            sender: self.identifier,
+            receiver,
+            value: self.evaluate(receiver)?,
+        })
+    }
+
+    /// Check a point from another participant agrees with this polynomial,
+    /// i.e. `f_j(i) == f_i(j)`.
+    ///
+    /// This doesn't know the threshold so use [`Bivariate::verify_point`]
+    /// to also check the degree.
+    pub fn verify_point(&self, point: &BivariatePoint<F>) -> bool {
+        point.receiver == self.identifier
+            && point.sender != 0
+            && self
+                .evaluate(point.sender)
+                .map(|v| v == point.value)
+                .unwrap_or(false)
+    }
+
+    fn evaluate(&self, x: u8) -> Result<F, Error> {
+        if self.coefficients.is_empty() {
+            return Err(Error::InvalidShare);
+        }
+        let polynomial = Polynomial {
+            coefficients: self.coefficients.clone(),
+        };
+        Ok(polynomial.evaluate(F::from(x as u64), self.coefficients.len()))
+    }
+}
@@ -0,0 +1,240 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{FeldmanVerifier, Shamir, Share};
+use crate::{Error, HashToGroup};
+use bls12_381_plus::{
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Scalar,
+};
+use core::marker::PhantomData;
+use elliptic_curve::group::{Group, GroupEncoding, ScalarMul};
+
+/// The basic scheme DST for signatures in G2 with public keys in G1
+pub const BLS_MIN_PK_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+/// The proof of possession scheme DST for signatures in G2 with public keys in G1
+pub const BLS_MIN_PK_POP_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// The basic scheme DST for signatures in G1 with public keys in G2
+pub const BLS_MIN_SIG_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+/// The proof of possession scheme DST for signatures in G1 with public keys in G2
+pub const BLS_MIN_SIG_POP_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A BLS signature ciphersuite from
+/// <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05#section-4.2>
+pub trait BlsSignatureVariant {
+    /// The group for public keys and public shares
+    type PublicKey: Group<Scalar = Scalar> + GroupEncoding + Default + ScalarMul<Scalar>;
+    /// The group for signatures and partial signatures
+    type Signature: HashToGroup<Scalar = Scalar> + Default + ScalarMul<Scalar>;
+
+    /// The domain separation tag used to hash messages
+    const DST: &'static [u8];
+
+    /// Check `e(signature, g) == e(H(m), public_key)`
+    fn pairing_check(
+        public_key: &Self::PublicKey,
+        message: &Self::Signature,
+        signature: &Self::Signature,
+    ) -> bool;
+}
+
+/// Minimal public key size: public keys in G1 and signatures in G2
+/// with the basic scheme DST
+#[derive(Copy, Clone, Debug)]
+pub struct BlsMinPk;
+
+/// Minimal signature size: public keys in G2 and signatures in G1
+/// with the basic scheme DST
+#[derive(Copy, Clone, Debug)]
+pub struct BlsMinSig;
+
+/// [`BlsMinPk`] with the proof of possession scheme DST
+#[derive(Copy, Clone, Debug)]
+pub struct BlsMinPkPop;
+
+/// [`BlsMinSig`] with the proof of possession scheme DST
+#[derive(Copy, Clone, Debug)]
+pub struct BlsMinSigPop;
+
+fn min_pk_pairing_check(
+    public_key: &G1Projective,
+    message: &G2Projective,
+    signature: &G2Projective,
+) -> bool {
+    // e(-g1, σ) * e(pk, H(m)) == 1
+    let terms = [
+        (
+            &-G1Affine::generator(),
+            &G2Prepared::from(G2Affine::from(signature)),
+        ),
+        (
+            &G1Affine::from(public_key),
+            &G2Prepared::from(G2Affine::from(message)),
+        ),
+    ];
+    multi_miller_loop(&terms)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
+fn min_sig_pairing_check(
+    public_key: &G2Projective,
+    message: &G1Projective,
+    signature: &G1Projective,
+) -> bool {
+    // e(σ, -g2) * e(H(m), pk) == 1
+    let terms = [
+        (
+            &G1Affine::from(signature),
+            &G2Prepared::from(-G2Affine::generator()),
+        ),
+        (
+            &G1Affine::from(message),
+            &G2Prepared::from(G2Affine::from(public_key)),
+        ),
+    ];
+    multi_miller_loop(&terms)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
+impl BlsSignatureVariant for BlsMinPk {
+    type PublicKey = G1Projective;
+    type Signature = G2Projective;
+
+    const DST: &'static [u8] = BLS_MIN_PK_DST;
+
+    fn pairing_check(
+        public_key: &G1Projective,
+        message: &G2Projective,
+        signature: &G2Projective,
+    ) -> bool {
+        min_pk_pairing_check(public_key, message, signature)
+    }
+}
+
+impl BlsSignatureVariant for BlsMinPkPop {
+    type PublicKey = G1Projective;
+    type Signature = G2Projective;
+
+    const DST: &'static [u8] = BLS_MIN_PK_POP_DST;
+
+    fn pairing_check(
+        public_key: &G1Projective,
+        message: &G2Projective,
+        signature: &G2Projective,
+    ) -> bool {
+        min_pk_pairing_check(public_key, message, signature)
+    }
+}
+
+impl BlsSignatureVariant for BlsMinSig {
+    type PublicKey = G2Projective;
+    type Signature = G1Projective;
+
+    const DST: &'static [u8] = BLS_MIN_SIG_DST;
+
+    fn pairing_check(
+        public_key: &G2Projective,
+        message: &G1Projective,
+        signature: &G1Projective,
+    ) -> bool {
+        min_sig_pairing_check(public_key, message, signature)
+    }
+}
+
+impl BlsSignatureVariant for BlsMinSigPop {
+    type PublicKey = G2Projective;
+    type Signature = G1Projective;
+
+    const DST: &'static [u8] = BLS_MIN_SIG_POP_DST;
+
+    fn pairing_check(
+        public_key: &G2Projective,
+        message: &G1Projective,
+        signature: &G1Projective,
+    ) -> bool {
+        min_sig_pairing_check(public_key, message, signature)
+    }
+}
+
+/// Threshold BLS signatures over BLS12-381.
+///
+/// The secret key is split with [`super::Feldman`] using the public key group
+/// so the verifier gives each share holder's public share.
+///
+/// 1. Every share holder calls `partial_sign` with its share of the secret key.
+/// 2. Anyone can check a partial signature with `verify_partial`.
+/// 3. `aggregate` combines the partial signatures with [`Shamir::combine_shares_group`]
+///    into a signature that verifies with the public key `verifier.commitments[0]`.
+#[derive(Copy, Clone, Debug)]
+pub struct ThresholdBls<V: BlsSignatureVariant> {
+    _marker: PhantomData<V>,
+}
+
+impl<V: BlsSignatureVariant> ThresholdBls<V> {
+    /// Sign `message` with a share of the secret key.
+    /// The partial signature is a share of the signature with the same identifier.
+    pub fn partial_sign(share: &Share, message: &[u8]) -> Result<Share, Error> {
+        let secret = share.as_field_element::<Scalar>()?;
+        let point = V::Signature::hash_to_group(message, V::DST)?;
+        Share::from_group_element(share.identifier(), point * secret)
+    }
+
+    /// Check a partial signature against the signer's public share
+    pub fn verify_partial(
+        partial: &Share,
+        verifier: &FeldmanVerifier<Scalar, V::PublicKey>,
+        message: &[u8],
+    ) -> Result<(), Error> {
+        if partial.identifier() == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        let signature = partial.as_group_element::<V::Signature>()?;
+        let public_share = verifier.public_share(partial.identifier());
+        Self::verify(&public_share, message, &signature)
+    }
+
+    /// Combine at least threshold partial signatures into a signature.
+    ///
+    /// Returns [`Error::InvalidSignature`] if any partial signature is invalid.
+    /// Use [`ThresholdBls::verify_partial`] to identify the culprits.
+    pub fn aggregate(
+        partials: &[Share],
+        verifier: &FeldmanVerifier<Scalar, V::PublicKey>,
+        message: &[u8],
+    ) -> Result<V::Signature, Error> {
+        let threshold = verifier.commitments.len();
+        if partials.len() < threshold {
+            return Err(Error::SharingMinThreshold);
+        }
+        for partial in partials {
+            Self::verify_partial(partial, verifier, message)?;
+        }
+        Shamir {
+            t: threshold,
+            n: partials.len(),
+        }
+        .combine_shares_group::<Scalar, V::Signature>(partials)
+    }
+
+    /// Check a signature over `message` is valid for `public_key`
+    pub fn verify(
+        public_key: &V::PublicKey,
+        message: &[u8],
+        signature: &V::Signature,
+    ) -> Result<(), Error> {
+        if bool::from(public_key.is_identity() | signature.is_identity()) {
+            return Err(Error::InvalidSignature);
+        }
+        let point = V::Signature::hash_to_group(message, V::DST)?;
+        if V::pairing_check(public_key, &point, signature) {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
@@ -0,0 +1,176 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use super::{
+    deserialize_scalar, deserialize_scalar_vec, serialize_scalar, serialize_scalar_vec, Shamir,
+    Share,
+};
+use crate::lib::*;
+use crate::Error;
+use elliptic_curve::ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Shamir secret sharing with Rabin–Ben-Or information checking from
+/// <https://dl.acm.org/doi/10.1145/73007.73014>.
+///
+/// Every pair of holders `(i, j)` gets a one-time MAC: holder `j` keeps the
+/// key `(b_ji, y_ji)` and holder `i` keeps the tag `c_ij = b_ji * s_i + y_ji`.
+/// Holder `i` sends holder `j` only the [`CheckedReveal`] from
+/// [`CheckedShare::reveal_to`] so the keys never leave their owner.
+/// A holder combining with the others' reveals checks each one with its own
+/// keys, so a modified share is detected except with probability `1 / |F|`
+/// regardless of the attacker's computing power. No commitments are needed.
+#[derive(Copy, Clone, Debug)]
+pub struct CheckedShamir {
+    /// The threshold necessary for combine
+    pub t: usize,
+    /// The number of shares to allocate
+    pub n: usize,
+}
+
+/// A Shamir share with the tags that authenticate it to the other holders
+/// and the keys that check the other holders' shares.
+/// This must be sent over a private channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckedShare<F: PrimeField> {
+    /// The Shamir share `s_i`
+    pub share: Share,
+    /// The tags `c_ij` where index `j - 1` is checked by holder `j`
+    #[serde(
+        serialize_with = "serialize_scalar_vec",
+        deserialize_with = "deserialize_scalar_vec"
+    )]
+    pub tags: Vec<F>,
+    /// The keys `b_ij` where index `j - 1` checks holder `j`
+    #[serde(
+        serialize_with = "serialize_scalar_vec",
+        deserialize_with = "deserialize_scalar_vec"
+    )]
+    pub key_multipliers: Vec<F>,
+    /// The keys `y_ij` where index `j - 1` checks holder `j`
+    #[serde(
+        serialize_with = "serialize_scalar_vec",
+        deserialize_with = "deserialize_scalar_vec"
+    )]
+    pub key_offsets: Vec<F>,
+}
+
+/// A share and the one tag that authenticates it to a single recipient
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckedReveal<F: PrimeField> {
+    /// The Shamir share `s_i`
+    pub share: Share,
+    /// The tag `c_ij` for recipient `j`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub tag: F,
+}
+
+impl CheckedShamir {
+    /// Create checked shares from a secret
+    pub fn split_secret<F, R>(&self, secret: F, rng: &mut R) -> Result<Vec<CheckedShare<F>>, Error>
+    where
+        F: PrimeField,
+        R: RngCore + CryptoRng,
+    {
+        let shares = self.shamir().split_secret::<F, R>(secret, rng)?;
+        let mut checked: Vec<CheckedShare<F>> = shares
+            .into_iter()
+            .map(|share| CheckedShare {
+                share,
+                tags: vec![F::zero(); self.n],
+                key_multipliers: vec![F::zero(); self.n],
+                key_offsets: vec![F::zero(); self.n],
+            })
+            .collect();
+
+        for i in 0..self.n {
+            let s = checked[i].share.as_field_element::<F>()?;
+            for j in (0..self.n).filter(|j| *j != i) {
+                let b = F::random(&mut *rng);
+                let y = F::random(&mut *rng);
+                checked[j].key_multipliers[i] = b;
+                checked[j].key_offsets[i] = y;
+                checked[i].tags[j] = b * s + y;
+            }
+        }
+        Ok(checked)
+    }
+
+    /// Check the other holders' reveals with `own` keys and reconstruct the secret.
+    ///
+    /// Returns [`Error::InvalidShare`] if any share fails its check.
+    /// Use [`CheckedShamir::culprits`] to identify them.
+    pub fn combine_shares<F: PrimeField>(
+        &self,
+        own: &CheckedShare<F>,
+        others: &[CheckedReveal<F>],
+    ) -> Result<F, Error> {
+        if !self.culprits(own, others).is_empty() {
+            return Err(Error::InvalidShare);
+        }
+        let mut shares = Vec::with_capacity(others.len() + 1);
+        shares.push(own.share.clone());
+        shares.extend(others.iter().map(|s| s.share.clone()));
+        self.shamir().combine_shares::<F>(&shares)
+    }
+
+    /// The identifiers of the other holders whose reveals fail the check with `own` keys
+    pub fn culprits<F: PrimeField>(
+        &self,
+        own: &CheckedShare<F>,
+        others: &[CheckedReveal<F>],
+    ) -> Vec<u8> {
+        others
+            .iter()
+            .filter(|s| !own.check(s))
+            .map(|s| s.share.identifier())
+            .collect()
+    }
+
+    fn shamir(&self) -> Shamir {
+        Shamir {
+            t: self.t,
+            n: self.n,
+        }
+    }
+}
+
+impl<F: PrimeField> CheckedShare<F> {
+    /// The share and tag to send to holder `recipient`, without any keys
+    pub fn reveal_to(&self, recipient: u8) -> Result<CheckedReveal<F>, Error> {
+        if recipient == 0 || recipient == self.share.identifier() {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        let tag = self
+            .tags
+            .get(recipient as usize - 1)
+            .ok_or(Error::SharingInvalidIdentifier)?;
+        Ok(CheckedReveal {
+            share: self.share.clone(),
+            tag: *tag,
+        })
+    }
+
+    /// Check another holder's reveal against this holder's keys
+    pub fn check(&self, other: &CheckedReveal<F>) -> bool {
+        let i = self.share.identifier() as usize;
+        let j = other.share.identifier() as usize;
+        if i == 0 || j == 0 || i == j {
+            return false;
+        }
+        let (b, y) = match (self.key_multipliers.get(j - 1), self.key_offsets.get(j - 1)) {
+            (Some(b), Some(y)) => (b, y),
+            _ => return false,
+        };
+        other
+            .share
+            .as_field_element::<F>()
+            .map(|s| *b * s + y == other.tag)
+            .unwrap_or(false)
+    }
+}
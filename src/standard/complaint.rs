@@ -0,0 +1,230 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{
+    serialize_group, DealerSignature, DealerVerifyingKey, DleqProof, FeldmanVerifier,
+    PedersenVerifier, Share, SignedShare,
+};
+use crate::util::deserialize_group;
+use crate::Error;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// The proof a dealer sent the accused share
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ComplaintEvidence<F: PrimeField, G: Group + GroupEncoding> {
+    /// The plaintext shares with the dealer's
+    /// [`DealerSignature::sign_plaintext_share`] signature
+    Signed {
+        /// The accused share
+        share: Share,
+        /// The accused Pedersen blind share if any
+        blind_share: Option<Share>,
+        /// The dealer's signature over the shares
+        signature: DealerSignature,
+    },
+    /// The dealer signed share envelope opened with the ECDH
+    /// shared point `g^{rx}` instead of the accuser's secret key `x`
+    Opened {
+        /// The dealer signed envelope which binds the accuser's public key `g^x`
+        #[serde(bound(serialize = "SignedShare<G>: Serialize"))]
+        #[serde(bound(deserialize = "SignedShare<G>: Deserialize<'de>"))]
+        envelope: SignedShare<G>,
+        /// The shared point `(g^r)^x`
+        #[serde(
+            serialize_with = "serialize_group",
+            deserialize_with = "deserialize_group"
+        )]
+        shared_secret: G,
+        /// The proof `log_g(g^x) == log_{g^r}(g^{rx})`
+        #[serde(bound(serialize = "DleqProof<F>: Serialize"))]
+        #[serde(bound(deserialize = "DleqProof<F>: Deserialize<'de>"))]
+        proof: DleqProof<F>,
+    },
+}
+
+/// The outcome of judging a [`ShareComplaint`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComplaintVerdict {
+    /// The dealer sent a share that does not match its commitments
+    /// or an envelope that does not decrypt to a share
+    Upheld,
+    /// The evidence is invalid or the share matches the commitments
+    Dismissed,
+}
+
+/// A publicly verifiable accusation that a dealer sent a share
+/// which fails [`FeldmanVerifier::verify`] or [`PedersenVerifier::verify`].
+///
+/// Anyone holding the dealer's public key and verifier can run
+/// [`ShareComplaint::judge_feldman`] or [`ShareComplaint::judge_pedersen`]
+/// to decide between the dealer and the accuser. Complaining reveals the
+/// accused share but not the accuser's long-term secret key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShareComplaint<F: PrimeField, G: Group + GroupEncoding> {
+    /// The hash of the dealer's verifier
+    pub verifier_hash: [u8; 32],
+    /// The proof the dealer sent the share
+    #[serde(bound(serialize = "ComplaintEvidence<F, G>: Serialize"))]
+    #[serde(bound(deserialize = "ComplaintEvidence<F, G>: Deserialize<'de>"))]
+    pub evidence: ComplaintEvidence<F, G>,
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> ShareComplaint<F, G> {
+    /// Complain about a plaintext share the dealer signed
+    pub fn from_signed_share(
+        share: Share,
+        blind_share: Option<Share>,
+        signature: DealerSignature,
+        verifier_hash: [u8; 32],
+    ) -> Self {
+        Self {
+            verifier_hash,
+            evidence: ComplaintEvidence::Signed {
+                share,
+                blind_share,
+                signature,
+            },
+        }
+    }
+
+    /// Complain about the share in a dealer signed envelope
+    /// by proving how it decrypts with the accuser's `secret_key`
+    pub fn from_envelope<R: RngCore + CryptoRng>(
+        envelope: SignedShare<G>,
+        secret_key: &F,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        let ephemeral_key = envelope.share.ephemeral_key;
+        if bool::from(ephemeral_key.is_identity())
+            || G::generator() * *secret_key != envelope.recipient
+        {
+            return Err(Error::InvalidCiphertext);
+        }
+        Ok(Self {
+            verifier_hash: envelope.verifier_hash,
+            evidence: ComplaintEvidence::Opened {
+                shared_secret: ephemeral_key * *secret_key,
+                proof: DleqProof::new(
+                    *secret_key,
+                    G::generator(),
+                    ephemeral_key,
+                    &envelope.verifier_hash,
+                    rng,
+                ),
+                envelope,
+            },
+        })
+    }
+
+    /// Decide a complaint against a Feldman dealing
+    pub fn judge_feldman<K: DealerVerifyingKey>(
+        &self,
+        verifier: &FeldmanVerifier<F, G>,
+        dealer_key: &K,
+    ) -> ComplaintVerdict {
+        if verifier.hash() != self.verifier_hash {
+            return ComplaintVerdict::Dismissed;
+        }
+        match self.accused_shares(false, dealer_key) {
+            Ok(None) => ComplaintVerdict::Upheld,
+            Ok(Some((share, None))) if !verifier.verify(&share) => ComplaintVerdict::Upheld,
+            _ => ComplaintVerdict::Dismissed,
+        }
+    }
+
+    /// Decide a complaint against a Pedersen dealing
+    pub fn judge_pedersen<K: DealerVerifyingKey>(
+        &self,
+        verifier: &PedersenVerifier<F, G>,
+        dealer_key: &K,
+    ) -> ComplaintVerdict {
+        if verifier.hash() != self.verifier_hash {
+            return ComplaintVerdict::Dismissed;
+        }
+        match self.accused_shares(true, dealer_key) {
+            Ok(None) => ComplaintVerdict::Upheld,
+            Ok(Some((share, Some(blind_share)))) if !verifier.verify(&share, &blind_share) => {
+                ComplaintVerdict::Upheld
+            }
+            _ => ComplaintVerdict::Dismissed,
+        }
+    }
+
+    /// Check the evidence and return the shares the dealer sent.
+    ///
+    /// Returns `Ok(None)` if the evidence holds but the dealer's
+    /// envelope does not decrypt to a well formed share.
+    fn accused_shares<K: DealerVerifyingKey>(
+        &self,
+        pedersen: bool,
+        dealer_key: &K,
+    ) -> Result<Option<(Share, Option<Share>)>, Error> {
+        match &self.evidence {
+            ComplaintEvidence::Signed {
+                share,
+                blind_share,
+                signature,
+            } => {
+                signature.verify_plaintext_share(
+                    share,
+                    blind_share.as_ref(),
+                    &self.verifier_hash,
+                    dealer_key,
+                )?;
+                if let Some(blind_share) = blind_share {
+                    if blind_share.identifier() != share.identifier() {
+                        return Err(Error::InvalidShare);
+                    }
+                }
+                Ok(Some((share.clone(), blind_share.clone())))
+            }
+            ComplaintEvidence::Opened {
+                envelope,
+                shared_secret,
+                proof,
+            } => {
+                // The signature binds the recipient so the accuser can't substitute their key
+                envelope.verify(&self.verifier_hash, dealer_key)?;
+                let ephemeral_key = envelope.share.ephemeral_key;
+                if !proof.verify(
+                    G::generator(),
+                    envelope.recipient,
+                    ephemeral_key,
+                    *shared_secret,
+                    &self.verifier_hash,
+                ) {
+                    return Err(Error::InvalidProof);
+                }
+                // The shared point is proven correct so any failure
+                // from here on is the dealer's fault
+                let plaintext = match envelope.share.open_shared(
+                    shared_secret,
+                    &envelope.recipient,
+                    &self.verifier_hash,
+                ) {
+                    Ok(plaintext) => plaintext,
+                    Err(_) => return Ok(None),
+                };
+                let shares = if pedersen {
+                    envelope
+                        .share
+                        .plaintext_pedersen_shares(plaintext)
+                        .map(|(share, blind_share)| (share, Some(blind_share)))
+                } else {
+                    envelope
+                        .share
+                        .plaintext_share(plaintext)
+                        .map(|share| (share, None))
+                };
+                Ok(shares.ok())
+            }
+        }
+    }
+}
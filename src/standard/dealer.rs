@@ -0,0 +1,309 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{
+    deserialize_byte_vec, serialize_byte_vec, serialize_group, EncryptedShare, FeldmanVerifier,
+    PedersenVerifier, Share,
+};
+use crate::lib::*;
+use crate::util::deserialize_group;
+use crate::Error;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use serde::{Deserialize, Serialize};
+
+const DEALER_VERIFIER_SIGNATURE_DST: &[u8] = b"VSSS-RS-V01-DEALER-SIGNED-VERIFIER";
+const DEALER_SHARE_SIGNATURE_DST: &[u8] = b"VSSS-RS-V01-DEALER-SIGNED-SHARE";
+const DEALER_PLAINTEXT_SHARE_SIGNATURE_DST: &[u8] = b"VSSS-RS-V01-DEALER-SIGNED-PLAINTEXT-SHARE";
+
+/// A dealer's long-term key used to sign its dealings
+pub trait DealerSigningKey {
+    /// Sign `msg` and return the encoded signature
+    fn sign_dealing(&self, msg: &[u8]) -> Vec<u8>;
+}
+
+/// The public half of a [`DealerSigningKey`]
+pub trait DealerVerifyingKey {
+    /// Check the encoded `signature` over `msg`
+    fn verify_dealing(&self, msg: &[u8], signature: &[u8]) -> bool;
+}
+
+/// A dealer's signature over a verifier or a share envelope.
+///
+/// Receivers check the commitments and their share came from the expected
+/// dealer before checking the share against the commitments.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealerSignature(
+    #[serde(
+        serialize_with = "serialize_byte_vec",
+        deserialize_with = "deserialize_byte_vec"
+    )]
+    pub Vec<u8>,
+);
+
+impl DealerSignature {
+    /// Sign a Feldman verifier
+    pub fn sign_feldman<F, G, K>(verifier: &FeldmanVerifier<F, G>, key: &K) -> Self
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        K: DealerSigningKey,
+    {
+        Self(key.sign_dealing(&verifier_message(&verifier.hash())))
+    }
+
+    /// Check the signature over a Feldman verifier
+    pub fn verify_feldman<F, G, K>(
+        &self,
+        verifier: &FeldmanVerifier<F, G>,
+        key: &K,
+    ) -> Result<(), Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        K: DealerVerifyingKey,
+    {
+        self.check(key, &verifier_message(&verifier.hash()))
+    }
+
+    /// Sign a Pedersen verifier
+    pub fn sign_pedersen<F, G, K>(verifier: &PedersenVerifier<F, G>, key: &K) -> Self
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        K: DealerSigningKey,
+    {
+        Self(key.sign_dealing(&verifier_message(&verifier.hash())))
+    }
+
+    /// Check the signature over a Pedersen verifier
+    pub fn verify_pedersen<F, G, K>(
+        &self,
+        verifier: &PedersenVerifier<F, G>,
+        key: &K,
+    ) -> Result<(), Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        K: DealerVerifyingKey,
+    {
+        self.check(key, &verifier_message(&verifier.hash()))
+    }
+
+    /// Sign a share envelope encrypted to `recipient`
+    /// for the dealing identified by `verifier_hash`
+    pub fn sign_share<G, K>(
+        share: &EncryptedShare<G>,
+        recipient: &G,
+        verifier_hash: &[u8; 32],
+        key: &K,
+    ) -> Self
+    where
+        G: Group + GroupEncoding,
+        K: DealerSigningKey,
+    {
+        Self(key.sign_dealing(&share_message(share, recipient, verifier_hash)))
+    }
+
+    /// Check the signature over a share envelope encrypted to `recipient`
+    /// for the dealing identified by `verifier_hash`
+    pub fn verify_share<G, K>(
+        &self,
+        share: &EncryptedShare<G>,
+        recipient: &G,
+        verifier_hash: &[u8; 32],
+        key: &K,
+    ) -> Result<(), Error>
+    where
+        G: Group + GroupEncoding,
+        K: DealerVerifyingKey,
+    {
+        self.check(key, &share_message(share, recipient, verifier_hash))
+    }
+
+    /// Sign a plaintext share and optional Pedersen blind share
+    /// for the dealing identified by `verifier_hash`.
+    ///
+    /// Only send this over a private channel. The accuser reveals it in a
+    /// [`super::ShareComplaint`] to prove which share the dealer sent.
+    pub fn sign_plaintext_share<K: DealerSigningKey>(
+        share: &Share,
+        blind_share: Option<&Share>,
+        verifier_hash: &[u8; 32],
+        key: &K,
+    ) -> Self {
+        Self(key.sign_dealing(&plaintext_share_message(share, blind_share, verifier_hash)))
+    }
+
+    /// Check the signature over a plaintext share and optional Pedersen blind share
+    pub fn verify_plaintext_share<K: DealerVerifyingKey>(
+        &self,
+        share: &Share,
+        blind_share: Option<&Share>,
+        verifier_hash: &[u8; 32],
+        key: &K,
+    ) -> Result<(), Error> {
+        self.check(
+            key,
+            &plaintext_share_message(share, blind_share, verifier_hash),
+        )
+    }
+
+    fn check<K: DealerVerifyingKey>(&self, key: &K, msg: &[u8]) -> Result<(), Error> {
+        if key.verify_dealing(msg, &self.0) {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
+
+/// A share envelope signed by the dealer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedShare<G: Group + GroupEncoding> {
+    /// The hash of the verifier for the dealing
+    pub verifier_hash: [u8; 32],
+    /// The recipient's public key `g^x` the share is encrypted to
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub recipient: G,
+    /// The encrypted share
+    #[serde(bound(serialize = "EncryptedShare<G>: Serialize"))]
+    #[serde(bound(deserialize = "EncryptedShare<G>: Deserialize<'de>"))]
+    pub share: EncryptedShare<G>,
+    /// The dealer's signature over the hash, recipient and share
+    pub signature: DealerSignature,
+}
+
+impl<G: Group + GroupEncoding> SignedShare<G> {
+    /// Sign `share` encrypted to `recipient`
+    /// for the dealing identified by `verifier_hash`
+    pub fn new<K: DealerSigningKey>(
+        share: EncryptedShare<G>,
+        recipient: G,
+        verifier_hash: [u8; 32],
+        key: &K,
+    ) -> Self {
+        let signature = DealerSignature::sign_share(&share, &recipient, &verifier_hash, key);
+        Self {
+            verifier_hash,
+            recipient,
+            share,
+            signature,
+        }
+    }
+
+    /// Check the dealer signed this share for the dealing identified by `verifier_hash`
+    pub fn verify<K: DealerVerifyingKey>(
+        &self,
+        verifier_hash: &[u8; 32],
+        key: &K,
+    ) -> Result<(), Error> {
+        if &self.verifier_hash != verifier_hash {
+            return Err(Error::InvalidSignature);
+        }
+        self.signature
+            .verify_share(&self.share, &self.recipient, &self.verifier_hash, key)
+    }
+}
+
+fn verifier_message(verifier_hash: &[u8; 32]) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(DEALER_VERIFIER_SIGNATURE_DST.len() + verifier_hash.len());
+    msg.extend_from_slice(DEALER_VERIFIER_SIGNATURE_DST);
+    msg.extend_from_slice(verifier_hash);
+    msg
+}
+
+fn share_message<G: Group + GroupEncoding>(
+    share: &EncryptedShare<G>,
+    recipient: &G,
+    verifier_hash: &[u8; 32],
+) -> Vec<u8> {
+    let recipient = recipient.to_bytes();
+    let ephemeral_key = share.ephemeral_key.to_bytes();
+    let mut msg = Vec::new();
+    msg.extend_from_slice(DEALER_SHARE_SIGNATURE_DST);
+    msg.extend_from_slice(verifier_hash);
+    msg.push(share.identifier);
+    msg.extend_from_slice(recipient.as_ref());
+    msg.extend_from_slice(ephemeral_key.as_ref());
+    msg.extend_from_slice(&share.ciphertext);
+    msg
+}
+
+fn plaintext_share_message(
+    share: &Share,
+    blind_share: Option<&Share>,
+    verifier_hash: &[u8; 32],
+) -> Vec<u8> {
+    let mut msg = Vec::new();
+    msg.extend_from_slice(DEALER_PLAINTEXT_SHARE_SIGNATURE_DST);
+    msg.extend_from_slice(verifier_hash);
+    msg.extend_from_slice(&(share.0.len() as u64).to_be_bytes());
+    msg.extend_from_slice(&share.0);
+    if let Some(blind_share) = blind_share {
+        msg.extend_from_slice(&blind_share.0);
+    }
+    msg
+}
+
+#[cfg(any(feature = "ed25519", test))]
+impl DealerSigningKey for ed25519_dalek::Keypair {
+    fn sign_dealing(&self, msg: &[u8]) -> Vec<u8> {
+        use ed25519_dalek::Signer;
+        self.sign(msg).to_bytes().to_vec()
+    }
+}
+
+#[cfg(any(feature = "ed25519", test))]
+impl DealerVerifyingKey for ed25519_dalek::PublicKey {
+    fn verify_dealing(&self, msg: &[u8], signature: &[u8]) -> bool {
+        ed25519_dalek::Signature::try_from(signature)
+            .map(|s| self.verify_strict(msg, &s).is_ok())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(any(all(feature = "ecdsa", feature = "secp256k1"), test))]
+impl DealerSigningKey for k256::ecdsa::SigningKey {
+    fn sign_dealing(&self, msg: &[u8]) -> Vec<u8> {
+        use k256::ecdsa::signature::Signer;
+        let signature: k256::ecdsa::Signature = self.sign(msg);
+        signature.to_vec()
+    }
+}
+
+#[cfg(any(all(feature = "ecdsa", feature = "secp256k1"), test))]
+impl DealerVerifyingKey for k256::ecdsa::VerifyingKey {
+    fn verify_dealing(&self, msg: &[u8], signature: &[u8]) -> bool {
+        use k256::ecdsa::signature::Verifier;
+        k256::ecdsa::Signature::try_from(signature)
+            .map(|s| self.verify(msg, &s).is_ok())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(any(all(feature = "ecdsa", feature = "p256"), test))]
+impl DealerSigningKey for p256::ecdsa::SigningKey {
+    fn sign_dealing(&self, msg: &[u8]) -> Vec<u8> {
+        use p256::ecdsa::signature::Signer;
+        let signature: p256::ecdsa::Signature = self.sign(msg);
+        signature.as_ref().to_vec()
+    }
+}
+
+#[cfg(any(all(feature = "ecdsa", feature = "p256"), test))]
+impl DealerVerifyingKey for p256::ecdsa::VerifyingKey {
+    fn verify_dealing(&self, msg: &[u8], signature: &[u8]) -> bool {
+        use p256::ecdsa::signature::Verifier;
+        p256::ecdsa::Signature::try_from(signature)
+            .map(|s| self.verify(msg, &s).is_ok())
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,199 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{serialize_group, DleqProof, FeldmanVerifier, Shamir, Share};
+use crate::lib::*;
+use crate::util::deserialize_group;
+use crate::Error;
+use core::ops::Add;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// An ElGamal ciphertext `(g^r, M * Y^r)` of the group element `M`
+/// encrypted to the group key `Y` of a Feldman dealing.
+///
+/// Ciphertexts are additively homomorphic so encrypting `g^m` allows
+/// summing votes or bids before a single threshold decryption.
+/// Recovering `m` from `g^m` is left to the caller.
+///
+/// This is only secure against chosen plaintext attacks (IND-CPA).
+/// The same homomorphism makes ciphertexts malleable: anyone can turn
+/// `(c1, c2)` into an encryption of a related message without knowing it,
+/// and there is no proof of plaintext knowledge, so every share holder's
+/// [`ElGamalCiphertext::decryption_share`] decrypts any ciphertext it is given.
+/// Share holders must only decrypt ciphertexts the application has
+/// already accepted, such as a tally computed from authenticated ballots.
+/// Use a CCA-secure scheme like TDH2 when holders decrypt ciphertexts
+/// submitted by untrusted parties.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElGamalCiphertext<G: Group + GroupEncoding> {
+    /// The ephemeral key `c1 = g^r`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub c1: G,
+    /// The masked message `c2 = M * Y^r`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub c2: G,
+}
+
+/// A share holder's partial decryption `c1^{s_i}` with a proof
+/// it used the same secret as its public share `g^{s_i}`
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct ElGamalDecryptionShare<F: PrimeField, G: Group + GroupEncoding> {
+    /// The share holder's identifier
+    pub identifier: u8,
+    /// The partial decryption `c1^{s_i}`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub value: G,
+    /// The proof `log_g(g^{s_i}) == log_{c1}(c1^{s_i})`
+    #[serde(bound(serialize = "DleqProof<F>: Serialize"))]
+    #[serde(bound(deserialize = "DleqProof<F>: Deserialize<'de>"))]
+    pub proof: DleqProof<F>,
+}
+
+impl<G: Group + GroupEncoding> ElGamalCiphertext<G> {
+    /// Encrypt `message` to the group key `verifier.commitments[0]`
+    pub fn encrypt<F, R>(
+        message: G,
+        verifier: &FeldmanVerifier<F, G>,
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let public_key = *verifier.commitments.first().ok_or(Error::InvalidShare)?;
+        let r = F::random(rng);
+        Ok(Self {
+            c1: verifier.generator * r,
+            c2: message + public_key * r,
+        })
+    }
+
+    /// Compute the partial decryption with a share of the secret key.
+    ///
+    /// Nothing about the ciphertext is checked, see [`ElGamalCiphertext`].
+    pub fn decryption_share<F, R>(
+        &self,
+        share: &Share,
+        verifier: &FeldmanVerifier<F, G>,
+        rng: &mut R,
+    ) -> Result<ElGamalDecryptionShare<F, G>, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        if share.identifier() == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        let secret = share.as_field_element::<F>()?;
+        Ok(ElGamalDecryptionShare {
+            identifier: share.identifier(),
+            value: self.c1 * secret,
+            proof: DleqProof::new(
+                secret,
+                verifier.generator,
+                self.c1,
+                &decryption_context(verifier, share.identifier()),
+                rng,
+            ),
+        })
+    }
+
+    /// Check a partial decryption against the share holder's public share
+    pub fn verify_decryption_share<F>(
+        &self,
+        share: &ElGamalDecryptionShare<F, G>,
+        verifier: &FeldmanVerifier<F, G>,
+    ) -> Result<(), Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+    {
+        if share.identifier == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        let public_share = verifier.public_share(share.identifier);
+        if share.proof.verify(
+            verifier.generator,
+            public_share,
+            self.c1,
+            share.value,
+            &decryption_context(verifier, share.identifier),
+        ) {
+            Ok(())
+        } else {
+            Err(Error::InvalidProof)
+        }
+    }
+
+    /// Check at least threshold partial decryptions, interpolate `c1^s`
+    /// in the exponent and return the message.
+    ///
+    /// Returns [`Error::InvalidProof`] if any partial decryption is invalid.
+    /// Use [`ElGamalCiphertext::verify_decryption_share`] to identify the culprits.
+    pub fn decrypt<F>(
+        &self,
+        shares: &[ElGamalDecryptionShare<F, G>],
+        verifier: &FeldmanVerifier<F, G>,
+    ) -> Result<G, Error>
+    where
+        F: PrimeField,
+        G: Default + ScalarMul<F>,
+    {
+        let threshold = verifier.commitments.len();
+        if shares.len() < threshold {
+            return Err(Error::SharingMinThreshold);
+        }
+        let mut partials = Vec::with_capacity(shares.len());
+        for share in shares {
+            self.verify_decryption_share(share, verifier)?;
+            partials.push(Share::from_group_element(share.identifier, share.value)?);
+        }
+        let mask = Shamir {
+            t: threshold,
+            n: shares.len(),
+        }
+        .combine_shares_group::<F, G>(&partials)?;
+        Ok(self.c2 - mask)
+    }
+}
+
+impl<G: Group + GroupEncoding> Add for ElGamalCiphertext<G> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            c1: self.c1 + rhs.c1,
+            c2: self.c2 + rhs.c2,
+        }
+    }
+}
+
+/// verifier hash || identifier
+fn decryption_context<F, G>(verifier: &FeldmanVerifier<F, G>, identifier: u8) -> [u8; 33]
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    let mut context = [0u8; 33];
+    context[..32].copy_from_slice(&verifier.hash());
+    context[32] = identifier;
+    context
+}
@@ -0,0 +1,377 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{Shamir, Share};
+use crate::lib::*;
+use crate::{CurveId, CurveIdentifier, Error};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+use elliptic_curve::ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use sha2_10::{Digest, Sha256};
+
+/// The current [`ShareEnvelope`] format version
+pub const SHARE_ENVELOPE_VERSION: u8 = 1;
+
+const SHARE_ENVELOPE_MAGIC: &[u8; 4] = b"VSSS";
+const SHARE_ENVELOPE_TEXT_PREFIX: &str = "vsss";
+const SET_ID_SIZE: usize = 16;
+const CHECKSUM_SIZE: usize = 4;
+
+/// The scheme that produced a share
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum ShareScheme {
+    /// [`Shamir`]
+    Shamir = 0,
+    /// [`super::Feldman`]
+    Feldman = 1,
+    /// [`super::Pedersen`]
+    Pedersen = 2,
+}
+
+impl ShareScheme {
+    /// A short lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Shamir => "shamir",
+            Self::Feldman => "feldman",
+            Self::Pedersen => "pedersen",
+        }
+    }
+}
+
+impl TryFrom<u8> for ShareScheme {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Shamir),
+            1 => Ok(Self::Feldman),
+            2 => Ok(Self::Pedersen),
+            _ => Err(Error::InvalidEnvelope),
+        }
+    }
+}
+
+/// A versioned, self-describing share.
+///
+/// Records the scheme, curve, threshold, total and the set the share was
+/// split into so shares from another curve or another split are rejected
+/// instead of combining to garbage. Both encodings end with a checksum.
+///
+/// The binary layout is
+///
+/// ```text
+/// "VSSS" || version || scheme || curve || t || n || set id (16)
+///        || share length (u16 BE) || identifier || value
+///        || SHA-256 of the preceding bytes truncated to 4 bytes
+/// ```
+///
+/// and the text layout is
+///
+/// ```text
+/// vsss<version>:<scheme>:<curve>:<t>:<n>:<set id hex>:<share hex>:<checksum hex>
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareEnvelope {
+    /// The format version
+    pub version: u8,
+    /// The scheme that produced the share
+    pub scheme: ShareScheme,
+    /// The field of the share value
+    pub curve: CurveId,
+    /// The threshold
+    pub threshold: u8,
+    /// The total number of shares
+    pub limit: u8,
+    /// Identifies the split the share belongs to
+    pub set_id: [u8; SET_ID_SIZE],
+    /// The share identifier and value
+    pub share: Share,
+}
+
+impl ShareEnvelope {
+    /// Wrap a share of the field `F`
+    pub fn new<F: PrimeField + CurveIdentifier>(
+        scheme: ShareScheme,
+        threshold: usize,
+        limit: usize,
+        set_id: [u8; SET_ID_SIZE],
+        share: Share,
+    ) -> Result<Self, Error> {
+        Shamir {
+            t: threshold,
+            n: limit,
+        }
+        .check_params()?;
+        let envelope = Self {
+            version: SHARE_ENVELOPE_VERSION,
+            scheme,
+            curve: F::CURVE_ID,
+            threshold: threshold as u8,
+            limit: limit as u8,
+            set_id,
+            share,
+        };
+        envelope.open::<F>()?;
+        Ok(envelope)
+    }
+
+    /// Wrap every share of a split under a fresh random set id
+    pub fn wrap_all<F, R>(
+        scheme: ShareScheme,
+        threshold: usize,
+        shares: &[Share],
+        rng: &mut R,
+    ) -> Result<Vec<Self>, Error>
+    where
+        F: PrimeField + CurveIdentifier,
+        R: RngCore + CryptoRng,
+    {
+        let mut set_id = [0u8; SET_ID_SIZE];
+        rng.fill_bytes(&mut set_id);
+        shares
+            .iter()
+            .map(|s| Self::new::<F>(scheme, threshold, shares.len(), set_id, s.clone()))
+            .collect()
+    }
+
+    /// Check the envelope holds a share of the field `F` and return it
+    pub fn open<F: PrimeField + CurveIdentifier>(&self) -> Result<Share, Error> {
+        let identifier = self.share.identifier();
+        if self.version != SHARE_ENVELOPE_VERSION
+            || self.curve != F::CURVE_ID
+            || self.share.value().len() != F::Repr::default().as_ref().len()
+            || identifier == 0
+            || identifier > self.limit
+        {
+            return Err(Error::InvalidEnvelope);
+        }
+        Ok(self.share.clone())
+    }
+
+    /// Check the envelopes all belong to the same split of the field `F`
+    /// and reconstruct the secret
+    pub fn combine<F: PrimeField + CurveIdentifier>(envelopes: &[Self]) -> Result<F, Error> {
+        let first = envelopes.first().ok_or(Error::SharingMinThreshold)?;
+        let mut shares = Vec::with_capacity(envelopes.len());
+        for envelope in envelopes {
+            if envelope.scheme != first.scheme
+                || envelope.threshold != first.threshold
+                || envelope.limit != first.limit
+                || envelope.set_id != first.set_id
+            {
+                return Err(Error::InvalidEnvelope);
+            }
+            shares.push(envelope.open::<F>()?);
+        }
+        Shamir {
+            t: first.threshold as usize,
+            n: first.limit as usize,
+        }
+        .combine_shares::<F>(&shares)
+    }
+
+    /// Encode to the binary layout
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            SHARE_ENVELOPE_MAGIC.len() + 7 + SET_ID_SIZE + self.share.0.len() + CHECKSUM_SIZE,
+        );
+        bytes.extend_from_slice(SHARE_ENVELOPE_MAGIC);
+        bytes.push(self.version);
+        bytes.push(self.scheme as u8);
+        bytes.push(self.curve as u8);
+        bytes.push(self.threshold);
+        bytes.push(self.limit);
+        bytes.extend_from_slice(&self.set_id);
+        bytes.extend_from_slice(&(self.share.0.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(&self.share.0);
+        let checksum = checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        bytes
+    }
+
+    /// Decode from the binary layout and check the checksum
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        const HEADER: usize = 4 + 5 + SET_ID_SIZE + 2;
+        if bytes.len() < HEADER + CHECKSUM_SIZE || &bytes[..4] != SHARE_ENVELOPE_MAGIC {
+            return Err(Error::InvalidEnvelope);
+        }
+        let (body, sum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if checksum(body) != sum {
+            return Err(Error::InvalidEnvelope);
+        }
+        let share_len = u16::from_be_bytes([body[HEADER - 2], body[HEADER - 1]]) as usize;
+        if body.len() != HEADER + share_len || share_len < 2 {
+            return Err(Error::InvalidEnvelope);
+        }
+        let mut set_id = [0u8; SET_ID_SIZE];
+        set_id.copy_from_slice(&body[9..9 + SET_ID_SIZE]);
+        Self::from_parts(
+            body[4],
+            ShareScheme::try_from(body[5])?,
+            CurveId::try_from(body[6])?,
+            body[7],
+            body[8],
+            set_id,
+            Share(body[HEADER..].to_vec()),
+        )
+    }
+
+    fn from_parts(
+        version: u8,
+        scheme: ShareScheme,
+        curve: CurveId,
+        threshold: u8,
+        limit: u8,
+        set_id: [u8; SET_ID_SIZE],
+        share: Share,
+    ) -> Result<Self, Error> {
+        let identifier = share.identifier();
+        if version != SHARE_ENVELOPE_VERSION
+            || threshold < 2
+            || limit < threshold
+            || identifier == 0
+            || identifier > limit
+        {
+            return Err(Error::InvalidEnvelope);
+        }
+        Ok(Self {
+            version,
+            scheme,
+            curve,
+            threshold,
+            limit,
+            set_id,
+            share,
+        })
+    }
+}
+
+impl Display for ShareEnvelope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = self.to_bytes();
+        write!(
+            f,
+            "{}{}:{}:{}:{}:{}:{}:{}:{}",
+            SHARE_ENVELOPE_TEXT_PREFIX,
+            self.version,
+            self.scheme.name(),
+            self.curve.name(),
+            self.threshold,
+            self.limit,
+            hex::encode(self.set_id),
+            hex::encode(&self.share.0),
+            hex::encode(&bytes[bytes.len() - CHECKSUM_SIZE..]),
+        )
+    }
+}
+
+impl FromStr for ShareEnvelope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s.split(':');
+        let mut next = || parts.next().ok_or(Error::InvalidEnvelope);
+        let version = next()?
+            .strip_prefix(SHARE_ENVELOPE_TEXT_PREFIX)
+            .and_then(|v| v.parse::<u8>().ok())
+            .ok_or(Error::InvalidEnvelope)?;
+        let scheme = next()?;
+        let scheme = [
+            ShareScheme::Shamir,
+            ShareScheme::Feldman,
+            ShareScheme::Pedersen,
+        ]
+        .into_iter()
+        .find(|v| v.name() == scheme)
+        .ok_or(Error::InvalidEnvelope)?;
+        let curve = next()?;
+        let curve = (1..=u8::MAX)
+            .map_while(|v| CurveId::try_from(v).ok())
+            .find(|v| v.name() == curve)
+            .ok_or(Error::InvalidEnvelope)?;
+        let threshold = next()?.parse::<u8>().map_err(|_| Error::InvalidEnvelope)?;
+        let limit = next()?.parse::<u8>().map_err(|_| Error::InvalidEnvelope)?;
+        let mut set_id = [0u8; SET_ID_SIZE];
+        hex::decode_to_slice(next()?, &mut set_id).map_err(|_| Error::InvalidEnvelope)?;
+        let share = Share(hex::decode(next()?).map_err(|_| Error::InvalidEnvelope)?);
+        let mut sum = [0u8; CHECKSUM_SIZE];
+        hex::decode_to_slice(next()?, &mut sum).map_err(|_| Error::InvalidEnvelope)?;
+        if parts.next().is_some() || share.0.len() < 2 {
+            return Err(Error::InvalidEnvelope);
+        }
+
+        let envelope = Self::from_parts(version, scheme, curve, threshold, limit, set_id, share)?;
+        let bytes = envelope.to_bytes();
+        if bytes[bytes.len() - CHECKSUM_SIZE..] != sum {
+            return Err(Error::InvalidEnvelope);
+        }
+        Ok(envelope)
+    }
+}
+
+impl Serialize for ShareEnvelope {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.collect_str(self)
+        } else {
+            s.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ShareEnvelope {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct EnvelopeVisitor;
+
+        impl<'de> Visitor<'de> for EnvelopeVisitor {
+            type Value = ShareEnvelope;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a share envelope string or bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ShareEnvelope::from_str(v)
+                    .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                ShareEnvelope::from_bytes(v)
+                    .map_err(|_| de::Error::invalid_value(de::Unexpected::Bytes(v), &self))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::new();
+                while let Some(b) = seq.next_element()? {
+                    bytes.push(b);
+                }
+                self.visit_bytes(&bytes)
+            }
+        }
+
+        if d.is_human_readable() {
+            d.deserialize_str(EnvelopeVisitor)
+        } else {
+            d.deserialize_bytes(EnvelopeVisitor)
+        }
+    }
+}
+
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_SIZE] {
+    let digest = Sha256::digest(bytes);
+    let mut sum = [0u8; CHECKSUM_SIZE];
+    sum.copy_from_slice(&digest[..CHECKSUM_SIZE]);
+    sum
+}
@@ -0,0 +1,12 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+mod ciphersuite;
+mod keygen;
+mod sign;
+
+pub use ciphersuite::*;
+pub use keygen::*;
+pub use sign::*;
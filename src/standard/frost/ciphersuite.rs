@@ -0,0 +1,192 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use crate::lib::*;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use zeroize::Zeroize;
+
+/// A FROST ciphersuite as defined in
+/// <https://www.rfc-editor.org/rfc/rfc9591.html#section-6>
+///
+/// Scalars are serialized with [`PrimeField::to_repr`] and elements
+/// with [`GroupEncoding::to_bytes`] which match the RFC encodings
+/// for the supplied ciphersuites.
+pub trait FrostCiphersuite {
+    /// The scalar field
+    type Scalar: PrimeField + Zeroize;
+    /// The prime order group
+    type Group: Group + GroupEncoding + Default + ScalarMul<Self::Scalar>;
+
+    /// The ciphersuite context string
+    const CONTEXT_STRING: &'static [u8];
+
+    /// Hash to a scalar for binding factors
+    fn h1(msgs: &[&[u8]]) -> Self::Scalar;
+    /// Hash to a scalar for the signature challenge
+    fn h2(msgs: &[&[u8]]) -> Self::Scalar;
+    /// Hash to a scalar for nonce generation
+    fn h3(msgs: &[&[u8]]) -> Self::Scalar;
+    /// Hash the message to be signed
+    fn h4(msgs: &[&[u8]]) -> Vec<u8>;
+    /// Hash the encoded commitment list
+    fn h5(msgs: &[&[u8]]) -> Vec<u8>;
+
+    /// Multiply by the cofactor before checking the signature equation.
+    /// Prime order groups leave the point alone.
+    fn clear_cofactor(point: Self::Group) -> Self::Group {
+        point
+    }
+}
+
+#[cfg(feature = "curve25519")]
+mod curve25519 {
+    use super::*;
+    use crate::curve25519::{WrappedEdwards, WrappedRistretto, WrappedScalar};
+    use curve25519_dalek::scalar::Scalar;
+    use sha2::{Digest, Sha512};
+
+    fn sha512(prefix: &[&[u8]], msgs: &[&[u8]]) -> [u8; 64] {
+        let mut hasher = Sha512::new();
+        for m in prefix.iter().chain(msgs) {
+            hasher.update(m);
+        }
+        let mut out = [0u8; 64];
+        out.copy_from_slice(&hasher.finalize());
+        out
+    }
+
+    fn sha512_to_scalar(prefix: &[&[u8]], msgs: &[&[u8]]) -> WrappedScalar {
+        WrappedScalar(Scalar::from_bytes_mod_order_wide(&sha512(prefix, msgs)))
+    }
+
+    /// FROST(Ed25519, SHA-512)
+    #[derive(Copy, Clone, Debug)]
+    pub struct FrostEd25519Sha512;
+
+    impl FrostCiphersuite for FrostEd25519Sha512 {
+        type Scalar = WrappedScalar;
+        type Group = WrappedEdwards;
+
+        const CONTEXT_STRING: &'static [u8] = b"FROST-ED25519-SHA512-v1";
+
+        fn h1(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[Self::CONTEXT_STRING, b"rho"], msgs)
+        }
+
+        /// The challenge is compatible with Ed25519 so it has no context
+        fn h2(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[], msgs)
+        }
+
+        fn h3(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[Self::CONTEXT_STRING, b"nonce"], msgs)
+        }
+
+        fn h4(msgs: &[&[u8]]) -> Vec<u8> {
+            sha512(&[Self::CONTEXT_STRING, b"msg"], msgs).to_vec()
+        }
+
+        fn h5(msgs: &[&[u8]]) -> Vec<u8> {
+            sha512(&[Self::CONTEXT_STRING, b"com"], msgs).to_vec()
+        }
+
+        fn clear_cofactor(point: WrappedEdwards) -> WrappedEdwards {
+            WrappedEdwards(point.0.mul_by_cofactor())
+        }
+    }
+
+    /// FROST(ristretto255, SHA-512)
+    #[derive(Copy, Clone, Debug)]
+    pub struct FrostRistretto255Sha512;
+
+    impl FrostCiphersuite for FrostRistretto255Sha512 {
+        type Scalar = WrappedScalar;
+        type Group = WrappedRistretto;
+
+        const CONTEXT_STRING: &'static [u8] = b"FROST-RISTRETTO255-SHA512-v1";
+
+        fn h1(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[Self::CONTEXT_STRING, b"rho"], msgs)
+        }
+
+        fn h2(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[Self::CONTEXT_STRING, b"chal"], msgs)
+        }
+
+        fn h3(msgs: &[&[u8]]) -> WrappedScalar {
+            sha512_to_scalar(&[Self::CONTEXT_STRING, b"nonce"], msgs)
+        }
+
+        fn h4(msgs: &[&[u8]]) -> Vec<u8> {
+            sha512(&[Self::CONTEXT_STRING, b"msg"], msgs).to_vec()
+        }
+
+        fn h5(msgs: &[&[u8]]) -> Vec<u8> {
+            sha512(&[Self::CONTEXT_STRING, b"com"], msgs).to_vec()
+        }
+    }
+}
+
+#[cfg(feature = "curve25519")]
+pub use self::curve25519::*;
+
+#[cfg(feature = "secp256k1")]
+mod secp256k1 {
+    use super::*;
+    use crate::secp256k1::{WrappedProjectivePoint, WrappedScalar};
+    use crate::util::hash_to_field;
+    use sha2_10::{Digest, Sha256};
+
+    fn sha256(prefix: &[&[u8]], msgs: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for m in prefix.iter().chain(msgs) {
+            hasher.update(m);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    /// FROST(secp256k1, SHA-256)
+    #[derive(Copy, Clone, Debug)]
+    pub struct FrostSecp256k1Sha256;
+
+    impl FrostSecp256k1Sha256 {
+        fn hash_to_scalar(tag: &[u8], msgs: &[&[u8]]) -> WrappedScalar {
+            hash_to_field::<WrappedScalar>(msgs, &[Self::CONTEXT_STRING, tag].concat())
+        }
+    }
+
+    impl FrostCiphersuite for FrostSecp256k1Sha256 {
+        type Scalar = WrappedScalar;
+        type Group = WrappedProjectivePoint;
+
+        const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-v1";
+
+        fn h1(msgs: &[&[u8]]) -> WrappedScalar {
+            Self::hash_to_scalar(b"rho", msgs)
+        }
+
+        fn h2(msgs: &[&[u8]]) -> WrappedScalar {
+            Self::hash_to_scalar(b"chal", msgs)
+        }
+
+        fn h3(msgs: &[&[u8]]) -> WrappedScalar {
+            Self::hash_to_scalar(b"nonce", msgs)
+        }
+
+        fn h4(msgs: &[&[u8]]) -> Vec<u8> {
+            sha256(&[Self::CONTEXT_STRING, b"msg"], msgs)
+        }
+
+        fn h5(msgs: &[&[u8]]) -> Vec<u8> {
+            sha256(&[Self::CONTEXT_STRING, b"com"], msgs)
+        }
+    }
+}
+
+#[cfg(feature = "secp256k1")]
+pub use self::secp256k1::*;
@@ -0,0 +1,383 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::super::{
+    deserialize_group_vec, deserialize_scalar, serialize_group, serialize_group_vec,
+    serialize_scalar, Feldman, FeldmanVerifier, Shamir, Share,
+};
+use crate::lib::*;
+use crate::util::{bytes_to_field, deserialize_group, hash_to_field};
+use crate::Error;
+use core::fmt::{self, Debug, Formatter};
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+const PROOF_OF_KNOWLEDGE_DST: &[u8] = b"VSSS-RS-V01-FROST-KEYGEN-PROOF-OF-KNOWLEDGE";
+
+/// A Schnorr proof of knowledge of the constant term of a dealer's polynomial
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct FrostProofOfKnowledge<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    /// The commitment `R = g^k`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub commitment: G,
+    /// The response `μ = k + a_0 * c`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub response: F,
+}
+
+/// The round 1 broadcast message containing the dealer's commitments
+/// and proof of knowledge of its secret
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrostKeygenRound1<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    /// The dealer's identifier
+    pub sender: u8,
+    /// The Feldman verifier for the dealer's polynomial
+    #[serde(bound(serialize = "FeldmanVerifier<F, G>: Serialize"))]
+    #[serde(bound(deserialize = "FeldmanVerifier<F, G>: Deserialize<'de>"))]
+    pub verifier: FeldmanVerifier<F, G>,
+    /// The proof of knowledge of the dealer's secret
+    #[serde(bound(serialize = "FrostProofOfKnowledge<F, G>: Serialize"))]
+    #[serde(bound(deserialize = "FrostProofOfKnowledge<F, G>: Deserialize<'de>"))]
+    pub proof: FrostProofOfKnowledge<F, G>,
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> FrostKeygenRound1<F, G> {
+    /// Check the proof of knowledge is valid for `context`
+    pub fn verify(&self, context: &[u8]) -> bool {
+        let public = match self.verifier.commitments.first() {
+            Some(c) => *c,
+            None => return false,
+        };
+        let c = challenge::<F, G>(self.sender, context, &public, &self.proof.commitment);
+        // g^μ * φ_0^-c == R
+        let lhs: G = self.verifier.generator * self.proof.response - public * c;
+        lhs == self.proof.commitment
+    }
+}
+
+/// The round 2 peer to peer message containing a participant's share
+/// from a dealer. This must be sent over a private channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrostKeygenRound2 {
+    /// The dealer's identifier
+    pub sender: u8,
+    /// The recipient's identifier
+    pub receiver: u8,
+    /// The share of the dealer's secret
+    pub share: Share,
+}
+
+/// The key material a participant needs for FROST signing
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FrostKeyPackage<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    /// The participant's identifier
+    pub identifier: u8,
+    /// The number of signers required to sign
+    pub threshold: usize,
+    /// The participant's secret signing share
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub signing_share: F,
+    /// The participant's public verifying share
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub verifying_share: G,
+    /// The group verifying key
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub verifying_key: G,
+    /// The verifying shares of every participant ordered by identifier
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub verifying_shares: Vec<G>,
+}
+
+impl<F, G> Debug for FrostKeyPackage<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    /// The signing share is left out
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrostKeyPackage")
+            .field("identifier", &self.identifier)
+            .field("threshold", &self.threshold)
+            .field("verifying_share", &self.verifying_share)
+            .field("verifying_key", &self.verifying_key)
+            .field("verifying_shares", &self.verifying_shares)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum FrostKeygenRound {
+    One,
+    Two,
+    Three,
+    Done,
+}
+
+/// A participant in the FROST distributed key generation.
+/// (see <https://eprint.iacr.org/2020/852.pdf> figure 1)
+///
+/// Every participant deals a random secret with Feldman and proves
+/// knowledge of it to prevent rogue key attacks.
+///
+/// 1. `round1` returns the broadcast with the commitments and proof of knowledge.
+/// 2. `receive_round1` checks everyone's proofs and returns the shares
+///    to send privately to each participant.
+/// 3. `receive_round2` checks the received shares and outputs the key package.
+///
+/// The `context` must be unique to this key generation
+/// and the same for all participants.
+/// The dealt shares are zeroized when dropped.
+#[derive(Clone)]
+pub struct FrostKeygenParticipant<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    id: u8,
+    threshold: usize,
+    limit: usize,
+    context: Vec<u8>,
+    round: FrostKeygenRound,
+    shares: Vec<Share>,
+    verifiers: BTreeMap<u8, FeldmanVerifier<F, G>>,
+}
+
+impl<F, G> FrostKeygenParticipant<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + Default + ScalarMul<F>,
+{
+    /// Create a new participant with `id` in a `threshold` of `limit` key generation
+    pub fn new(id: u8, threshold: usize, limit: usize, context: &[u8]) -> Result<Self, Error> {
+        Shamir {
+            t: threshold,
+            n: limit,
+        }
+        .check_params()?;
+        if id == 0 || id as usize > limit {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        Ok(Self {
+            id,
+            threshold,
+            limit,
+            context: context.to_vec(),
+            round: FrostKeygenRound::One,
+            shares: Vec::new(),
+            verifiers: BTreeMap::new(),
+        })
+    }
+
+    /// This participant's identifier
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Deal a random secret and prove knowledge of it.
+    /// The result is broadcast to everyone.
+    pub fn round1<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<FrostKeygenRound1<F, G>, Error> {
+        self.check_round(FrostKeygenRound::One)?;
+
+        let secret = F::random(&mut *rng);
+        let (shares, verifier) = Feldman {
+            t: self.threshold,
+            n: self.limit,
+        }
+        .split_secret::<F, G, R>(secret, None, rng)?;
+
+        let k = F::random(&mut *rng);
+        let commitment = verifier.generator * k;
+        let c = challenge::<F, G>(
+            self.id,
+            &self.context,
+            &verifier.commitments[0],
+            &commitment,
+        );
+        let proof = FrostProofOfKnowledge {
+            commitment,
+            response: k + secret * c,
+        };
+
+        self.shares = shares;
+        self.verifiers.insert(self.id, verifier.clone());
+        self.round = FrostKeygenRound::Two;
+        Ok(FrostKeygenRound1 {
+            sender: self.id,
+            verifier,
+            proof,
+        })
+    }
+
+    /// Check the proofs of knowledge from every other participant and
+    /// return the shares to send privately to each of them.
+    ///
+    /// Returns [`Error::InvalidProof`] if any broadcast is missing or invalid.
+    /// Use [`FrostKeygenRound1::verify`] to identify the culprits.
+    pub fn receive_round1(
+        &mut self,
+        broadcasts: &[FrostKeygenRound1<F, G>],
+    ) -> Result<Vec<FrostKeygenRound2>, Error> {
+        self.check_round(FrostKeygenRound::Two)?;
+
+        let generator = self.verifiers[&self.id].generator;
+        for dealer in self.others() {
+            let broadcast = broadcasts
+                .iter()
+                .find(|b| b.sender == dealer)
+                .ok_or(Error::InvalidProof)?;
+            if broadcast.verifier.generator != generator
+                || broadcast.verifier.commitments.len() != self.threshold
+                || !broadcast.verify(&self.context)
+            {
+                return Err(Error::InvalidProof);
+            }
+            self.verifiers.insert(dealer, broadcast.verifier.clone());
+        }
+
+        self.round = FrostKeygenRound::Three;
+        Ok(self
+            .shares
+            .iter()
+            .filter(|s| s.identifier() != self.id)
+            .map(|s| FrostKeygenRound2 {
+                sender: self.id,
+                receiver: s.identifier(),
+                share: s.clone(),
+            })
+            .collect())
+    }
+
+    /// Check the shares received from every other participant
+    /// and compute this participant's key package.
+    ///
+    /// Returns [`Error::InvalidShare`] if any share is missing or
+    /// does not match the dealer's commitments.
+    pub fn receive_round2(
+        &mut self,
+        shares: &[FrostKeygenRound2],
+    ) -> Result<FrostKeyPackage<F, G>, Error> {
+        self.check_round(FrostKeygenRound::Three)?;
+
+        let mut received = Vec::with_capacity(self.limit);
+        received.push(self.shares[self.id as usize - 1].clone());
+        for dealer in self.others() {
+            let share = shares
+                .iter()
+                .find(|s| s.sender == dealer && s.receiver == self.id)
+                .ok_or(Error::InvalidShare)?;
+            if share.share.identifier() != self.id || !self.verifiers[&dealer].verify(&share.share)
+            {
+                return Err(Error::InvalidShare);
+            }
+            received.push(share.share.clone());
+        }
+
+        let mut verifiers = self.verifiers.values();
+        let mut verifier = verifiers.next().ok_or(Error::InvalidShare)?.clone();
+        for v in verifiers {
+            verifier = (&verifier + v)?;
+        }
+        let mut share = Share::sum_field_elements::<F>(&received)?;
+        received.zeroize();
+        let signing_share = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare);
+        share.zeroize();
+        let signing_share = signing_share?;
+        let verifying_shares = verifier.public_shares(self.limit as u8);
+
+        self.shares.zeroize();
+        self.round = FrostKeygenRound::Done;
+        Ok(FrostKeyPackage {
+            identifier: self.id,
+            threshold: self.threshold,
+            signing_share,
+            verifying_share: verifying_shares[self.id as usize - 1],
+            verifying_key: verifier.commitments[0],
+            verifying_shares,
+        })
+    }
+
+    fn others(&self) -> impl Iterator<Item = u8> {
+        let id = self.id;
+        (1..=self.limit as u8).filter(move |i| *i != id)
+    }
+
+    fn check_round(&self, round: FrostKeygenRound) -> Result<(), Error> {
+        if self.round == round {
+            Ok(())
+        } else {
+            Err(Error::InvalidRound)
+        }
+    }
+}
+
+impl<F, G> Debug for FrostKeygenParticipant<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    /// The dealt shares are left out
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrostKeygenParticipant")
+            .field("id", &self.id)
+            .field("threshold", &self.threshold)
+            .field("limit", &self.limit)
+            .field("context", &self.context)
+            .field("round", &self.round)
+            .field("verifiers", &self.verifiers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, G> Drop for FrostKeygenParticipant<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    fn drop(&mut self) {
+        self.shares.zeroize();
+    }
+}
+
+/// c = H(i, Φ, g^{a_0}, R)
+fn challenge<F: PrimeField, G: GroupEncoding>(
+    id: u8,
+    context: &[u8],
+    public: &G,
+    commitment: &G,
+) -> F {
+    let context_len = (context.len() as u64).to_be_bytes();
+    hash_to_field::<F>(
+        &[
+            &[id],
+            &context_len,
+            context,
+            public.to_bytes().as_ref(),
+            commitment.to_bytes().as_ref(),
+        ],
+        PROOF_OF_KNOWLEDGE_DST,
+    )
+}
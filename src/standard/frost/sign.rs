@@ -0,0 +1,368 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::super::{deserialize_scalar, serialize_group, serialize_scalar};
+use super::{FrostCiphersuite, FrostKeyPackage};
+use crate::lib::*;
+use crate::util::{deserialize_group, lagrange};
+use crate::Error;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use elliptic_curve::{
+    ff::{Field, PrimeField},
+    group::{Group, GroupEncoding},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+/// The secret hiding and binding nonces for a single signature.
+/// These must never be reused and are consumed when signing
+/// so they can't be cloned. They are zeroized when dropped.
+pub struct FrostSigningNonces<F: PrimeField + Zeroize> {
+    /// The hiding nonce `d`
+    hiding: F,
+    /// The binding nonce `e`
+    binding: F,
+}
+
+impl<F: PrimeField + Zeroize> FrostSigningNonces<F> {
+    /// Derive the nonces from fresh randomness as in RFC 9591 section 4.1
+    pub(crate) fn from_randomness<C: FrostCiphersuite<Scalar = F>>(
+        hiding_randomness: &[u8; 32],
+        binding_randomness: &[u8; 32],
+        signing_share: &F,
+    ) -> Self {
+        Self {
+            hiding: nonce_generate::<C>(hiding_randomness, signing_share),
+            binding: nonce_generate::<C>(binding_randomness, signing_share),
+        }
+    }
+}
+
+impl<F: PrimeField + Zeroize> Debug for FrostSigningNonces<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrostSigningNonces").finish_non_exhaustive()
+    }
+}
+
+impl<F: PrimeField + Zeroize> Drop for FrostSigningNonces<F> {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+/// The public commitments to a participant's signing nonces
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct FrostSigningCommitments<G: Group + GroupEncoding> {
+    /// The participant's identifier
+    pub identifier: u8,
+    /// The hiding nonce commitment `D = g^d`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub hiding: G,
+    /// The binding nonce commitment `E = g^e`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub binding: G,
+}
+
+/// A participant's share of the signature
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct FrostSignatureShare<F: PrimeField> {
+    /// The participant's identifier
+    pub identifier: u8,
+    /// The signature share `z_i`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub share: F,
+}
+
+/// An aggregated Schnorr signature
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct FrostSignature<F: PrimeField, G: Group + GroupEncoding> {
+    /// The group commitment `R`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub commitment: G,
+    /// The response `z`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub response: F,
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding> FrostSignature<F, G> {
+    /// The signature encoding `R || z`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(self.commitment.to_bytes().as_ref());
+        out.extend_from_slice(self.response.to_repr().as_ref());
+        out
+    }
+}
+
+/// FROST threshold Schnorr signing as described in
+/// <https://www.rfc-editor.org/rfc/rfc9591.html>
+///
+/// 1. Every signer calls `commit` and sends the commitments to the coordinator
+///    who chooses the message and forwards all the commitments to the signers.
+/// 2. Every signer calls `sign` and returns the signature share.
+/// 3. The coordinator calls `aggregate` to check the shares and output the signature.
+///
+/// Key packages come from [`super::FrostKeygenParticipant`] or a trusted dealer.
+#[derive(Copy, Clone, Debug)]
+pub struct Frost<C: FrostCiphersuite> {
+    _marker: PhantomData<C>,
+}
+
+impl<C: FrostCiphersuite> Frost<C> {
+    /// Generate the nonces for a single signature and the commitments to send
+    /// to the coordinator.
+    pub fn commit<R: RngCore + CryptoRng>(
+        key_package: &FrostKeyPackage<C::Scalar, C::Group>,
+        rng: &mut R,
+    ) -> (
+        FrostSigningNonces<C::Scalar>,
+        FrostSigningCommitments<C::Group>,
+    ) {
+        let mut hiding_randomness = [0u8; 32];
+        let mut binding_randomness = [0u8; 32];
+        rng.fill_bytes(&mut hiding_randomness);
+        rng.fill_bytes(&mut binding_randomness);
+        let nonces = FrostSigningNonces::from_randomness::<C>(
+            &hiding_randomness,
+            &binding_randomness,
+            &key_package.signing_share,
+        );
+        hiding_randomness.zeroize();
+        binding_randomness.zeroize();
+        let commitments = FrostSigningCommitments {
+            identifier: key_package.identifier,
+            hiding: C::Group::generator() * nonces.hiding,
+            binding: C::Group::generator() * nonces.binding,
+        };
+        (nonces, commitments)
+    }
+
+    /// Compute this signer's share of the signature over `message`
+    /// given the commitments of every signer
+    pub fn sign(
+        key_package: &FrostKeyPackage<C::Scalar, C::Group>,
+        nonces: FrostSigningNonces<C::Scalar>,
+        commitments: &[FrostSigningCommitments<C::Group>],
+        message: &[u8],
+    ) -> Result<FrostSignatureShare<C::Scalar>, Error> {
+        let session = SigningSession::<C>::new(commitments, message, &key_package.verifying_key)?;
+        if session.participants.len() < key_package.threshold {
+            return Err(Error::SharingMinThreshold);
+        }
+        let (index, commitment) = session.find(key_package.identifier)?;
+        if commitment.hiding != C::Group::generator() * nonces.hiding
+            || commitment.binding != C::Group::generator() * nonces.binding
+        {
+            return Err(Error::InvalidSignature);
+        }
+
+        let lambda = lagrange::<C::Scalar>(key_package.identifier, &session.participants)?;
+        let share = nonces.hiding
+            + nonces.binding * session.binding_factors[index]
+            + lambda * key_package.signing_share * session.challenge;
+        Ok(FrostSignatureShare {
+            identifier: key_package.identifier,
+            share,
+        })
+    }
+
+    /// Check a signature share against the signer's verifying share
+    pub fn verify_signature_share(
+        share: &FrostSignatureShare<C::Scalar>,
+        verifying_share: &C::Group,
+        commitments: &[FrostSigningCommitments<C::Group>],
+        message: &[u8],
+        verifying_key: &C::Group,
+    ) -> Result<(), Error> {
+        let session = SigningSession::<C>::new(commitments, message, verifying_key)?;
+        session.verify_share(share, verifying_share)
+    }
+
+    /// Combine the signature shares into a signature.
+    /// `verifying_shares` are ordered by identifier as in [`FrostKeyPackage`].
+    ///
+    /// Returns [`Error::InvalidSignature`] if any share is missing or invalid.
+    /// Use [`Frost::verify_signature_share`] to identify the culprits.
+    pub fn aggregate(
+        commitments: &[FrostSigningCommitments<C::Group>],
+        message: &[u8],
+        shares: &[FrostSignatureShare<C::Scalar>],
+        verifying_key: &C::Group,
+        verifying_shares: &[C::Group],
+    ) -> Result<FrostSignature<C::Scalar, C::Group>, Error> {
+        let session = SigningSession::<C>::new(commitments, message, verifying_key)?;
+        if shares.len() != session.participants.len() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let mut response = C::Scalar::zero();
+        for id in &session.participants {
+            let share = shares
+                .iter()
+                .find(|s| s.identifier == *id)
+                .ok_or(Error::InvalidSignature)?;
+            let verifying_share = verifying_shares
+                .get(*id as usize - 1)
+                .ok_or(Error::SharingInvalidIdentifier)?;
+            session.verify_share(share, verifying_share)?;
+            response += share.share;
+        }
+        Ok(FrostSignature {
+            commitment: session.group_commitment,
+            response,
+        })
+    }
+
+    /// Check a signature over `message` is valid for `verifying_key`
+    pub fn verify(
+        verifying_key: &C::Group,
+        message: &[u8],
+        signature: &FrostSignature<C::Scalar, C::Group>,
+    ) -> Result<(), Error> {
+        let challenge = C::h2(&[
+            signature.commitment.to_bytes().as_ref(),
+            verifying_key.to_bytes().as_ref(),
+            message,
+        ]);
+        // g^z == R + PK^c
+        let lhs = C::Group::generator() * signature.response;
+        let rhs = signature.commitment + *verifying_key * challenge;
+        if C::clear_cofactor(lhs - rhs).is_identity().into() {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
+
+/// The values every signer and the coordinator derive from the commitment list
+pub(crate) struct SigningSession<C: FrostCiphersuite> {
+    commitments: Vec<FrostSigningCommitments<C::Group>>,
+    participants: Vec<u8>,
+    /// The binding factor of each participant ordered by identifier
+    pub(crate) binding_factors: Vec<C::Scalar>,
+    /// The group commitment `R`
+    pub(crate) group_commitment: C::Group,
+    challenge: C::Scalar,
+}
+
+impl<C: FrostCiphersuite> SigningSession<C> {
+    pub(crate) fn new(
+        commitments: &[FrostSigningCommitments<C::Group>],
+        message: &[u8],
+        verifying_key: &C::Group,
+    ) -> Result<Self, Error> {
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|c| c.identifier);
+        let participants: Vec<u8> = commitments.iter().map(|c| c.identifier).collect();
+        for (i, c) in commitments.iter().enumerate() {
+            if c.identifier == 0 {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if i > 0 && participants[i - 1] == c.identifier {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            if bool::from(c.hiding.is_identity() | c.binding.is_identity()) {
+                return Err(Error::InvalidSignature);
+            }
+        }
+
+        let mut encoded_commitments = Vec::new();
+        for c in &commitments {
+            encoded_commitments.extend_from_slice(serialize_identifier::<C>(c.identifier).as_ref());
+            encoded_commitments.extend_from_slice(c.hiding.to_bytes().as_ref());
+            encoded_commitments.extend_from_slice(c.binding.to_bytes().as_ref());
+        }
+        let verifying_key_bytes = verifying_key.to_bytes();
+        let message_hash = C::h4(&[message]);
+        let commitments_hash = C::h5(&[&encoded_commitments]);
+
+        let mut binding_factors = Vec::with_capacity(commitments.len());
+        let mut group_commitment = C::Group::identity();
+        for c in &commitments {
+            let binding_factor = C::h1(&[
+                verifying_key_bytes.as_ref(),
+                &message_hash,
+                &commitments_hash,
+                serialize_identifier::<C>(c.identifier).as_ref(),
+            ]);
+            group_commitment += c.hiding + c.binding * binding_factor;
+            binding_factors.push(binding_factor);
+        }
+        if group_commitment.is_identity().into() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let challenge = C::h2(&[
+            group_commitment.to_bytes().as_ref(),
+            verifying_key_bytes.as_ref(),
+            message,
+        ]);
+        Ok(Self {
+            commitments,
+            participants,
+            binding_factors,
+            group_commitment,
+            challenge,
+        })
+    }
+
+    fn find(&self, identifier: u8) -> Result<(usize, &FrostSigningCommitments<C::Group>), Error> {
+        self.commitments
+            .iter()
+            .enumerate()
+            .find(|(_, c)| c.identifier == identifier)
+            .ok_or(Error::SharingInvalidIdentifier)
+    }
+
+    fn verify_share(
+        &self,
+        share: &FrostSignatureShare<C::Scalar>,
+        verifying_share: &C::Group,
+    ) -> Result<(), Error> {
+        let (index, commitment) = self.find(share.identifier)?;
+        let lambda = lagrange::<C::Scalar>(share.identifier, &self.participants)?;
+        // g^z_i == D_i + E_i^ρ_i + Y_i^(c * λ_i)
+        let lhs = C::Group::generator() * share.share;
+        let rhs = commitment.hiding
+            + commitment.binding * self.binding_factors[index]
+            + *verifying_share * (self.challenge * lambda);
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
+
+fn serialize_identifier<C: FrostCiphersuite>(identifier: u8) -> <C::Scalar as PrimeField>::Repr {
+    C::Scalar::from(identifier as u64).to_repr()
+}
+
+/// nonce = H3(random_bytes || SerializeScalar(secret))
+pub(crate) fn nonce_generate<C: FrostCiphersuite>(
+    random_bytes: &[u8; 32],
+    secret: &C::Scalar,
+) -> C::Scalar {
+    C::h3(&[random_bytes, secret.to_repr().as_ref()])
+}
@@ -0,0 +1,685 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{
+    deserialize_group_vec, serialize_group, serialize_group_vec, FeldmanVerifier, Pedersen,
+    PedersenResult, PedersenVerifier, Polynomial, Ramp, Shamir, Share,
+};
+use crate::lib::*;
+use crate::util::{bytes_to_field, deserialize_group};
+use crate::{Error, HashToGroup};
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// The round 1 broadcast message containing the dealer's Pedersen commitments
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrRound1Broadcast<G: Group + GroupEncoding> {
+    /// The dealer's identifier
+    pub sender: u8,
+    /// The Pedersen commitments to the dealer's polynomials
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub commitments: Vec<G>,
+}
+
+/// The round 1 peer to peer message containing a participant's shares
+/// from a dealer. This must be sent over a private channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrRound1Share {
+    /// The dealer's identifier
+    pub sender: u8,
+    /// The recipient's identifier
+    pub receiver: u8,
+    /// The share of the dealer's secret
+    pub secret_share: Share,
+    /// The share of the dealer's blinding factor
+    pub blind_share: Share,
+}
+
+/// A broadcast complaint that a dealer's round 1 shares did not
+/// match its Pedersen commitments or were never received
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrComplaint {
+    /// The identifier of the complaining participant
+    pub accuser: u8,
+    /// The identifier of the dealer
+    pub accused: u8,
+}
+
+/// A dealer's broadcast response to a complaint revealing the shares
+/// it sent to the accuser
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrJustification {
+    /// The dealer's identifier
+    pub dealer: u8,
+    /// The identifier of the complaining participant
+    pub accuser: u8,
+    /// The accuser's share of the dealer's secret
+    pub secret_share: Share,
+    /// The accuser's share of the dealer's blinding factor
+    pub blind_share: Share,
+}
+
+/// The round 2 broadcast message containing the dealer's Feldman commitments
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrRound2Broadcast<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    /// The dealer's identifier
+    pub sender: u8,
+    /// The Feldman verifier for the dealer's secret polynomial
+    #[serde(bound(serialize = "FeldmanVerifier<F, G>: Serialize"))]
+    #[serde(bound(deserialize = "FeldmanVerifier<F, G>: Deserialize<'de>"))]
+    pub verifier: FeldmanVerifier<F, G>,
+}
+
+/// A broadcast complaint that a qualified dealer's Feldman commitments
+/// do not match the shares it sent. The shares are revealed so everyone
+/// can check they match the dealer's Pedersen commitments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrRound2Complaint {
+    /// The identifier of the complaining participant
+    pub accuser: u8,
+    /// The identifier of the dealer
+    pub accused: u8,
+    /// The accuser's share of the dealer's secret
+    pub secret_share: Share,
+    /// The accuser's share of the dealer's blinding factor
+    pub blind_share: Share,
+}
+
+/// A participant's broadcast shares of a dealer whose secret
+/// polynomial must be publicly reconstructed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GjkrReconstructionShare {
+    /// The identifier of the participant revealing its shares
+    pub sender: u8,
+    /// The identifier of the dealer being reconstructed
+    pub dealer: u8,
+    /// The sender's share of the dealer's secret
+    pub secret_share: Share,
+    /// The sender's share of the dealer's blinding factor
+    pub blind_share: Share,
+}
+
+/// The result of a successful distributed key generation
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GjkrOutput<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    /// This participant's share of the group secret
+    pub share: Share,
+    /// The group public key
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub public_key: G,
+    /// The public key shares of every participant ordered by identifier
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub public_shares: Vec<G>,
+    /// The identifiers of the dealers that contributed to the group secret
+    pub qualified: Vec<u8>,
+    /// The Feldman verifier for the group secret
+    #[serde(bound(serialize = "FeldmanVerifier<F, G>: Serialize"))]
+    #[serde(bound(deserialize = "FeldmanVerifier<F, G>: Deserialize<'de>"))]
+    pub verifier: FeldmanVerifier<F, G>,
+}
+
+impl<F, G> Debug for GjkrOutput<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    /// The secret share is left out
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GjkrOutput")
+            .field("public_key", &self.public_key)
+            .field("public_shares", &self.public_shares)
+            .field("qualified", &self.qualified)
+            .field("verifier", &self.verifier)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum GjkrRound {
+    One,
+    OneSent,
+    Complaints,
+    Qualified,
+    TwoReceived,
+    Reconstructing,
+    Done,
+}
+
+/// A participant in the Gennaro, Jarecki, Krawczyk, Rabin distributed key generation.
+/// (see <https://link.springer.com/content/pdf/10.1007/s00145-006-0347-3.pdf>)
+///
+/// Every participant is also a dealer. The protocol runs as follows
+/// with every message sent to all participants unless noted otherwise.
+///
+/// 1. `round1` deals a random secret with Pedersen. The shares are sent peer to peer.
+/// 2. `receive_round1` checks the shares and returns complaints against bad dealers.
+/// 3. `justify` answers complaints against this participant by revealing the shares.
+/// 4. `qualify` disqualifies dealers that failed to answer complaints
+///    and returns the qualified set.
+/// 5. `round2` reveals the Feldman commitments for this participant's secret.
+/// 6. `receive_round2` checks the shares against the Feldman commitments
+///    and returns complaints against inconsistent dealers.
+/// 7. `reconstruction_shares` reveals this participant's shares of every
+///    dealer with a valid complaint or missing Feldman commitments.
+/// 8. `complete` reconstructs those dealers' polynomials and outputs
+///    this participant's share of the group secret and the public keys.
+///
+/// Steps 7 and 8 must still be run when no complaints were made.
+#[derive(Clone)]
+pub struct GjkrParticipant<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> {
+    id: u8,
+    threshold: usize,
+    limit: usize,
+    round: GjkrRound,
+    share_generator: G,
+    blind_factor_generator: G,
+    dealing: Option<PedersenResult<F, G>>,
+    pedersen_commitments: BTreeMap<u8, Vec<G>>,
+    shares: BTreeMap<u8, (Share, Share)>,
+    disqualified: BTreeSet<u8>,
+    qualified: Vec<u8>,
+    feldman_verifiers: BTreeMap<u8, FeldmanVerifier<F, G>>,
+    reconstruct: BTreeSet<u8>,
+}
+
+impl<F, G> Debug for GjkrParticipant<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + ScalarMul<F>,
+{
+    /// The dealing and the received shares are left out
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GjkrParticipant")
+            .field("id", &self.id)
+            .field("threshold", &self.threshold)
+            .field("limit", &self.limit)
+            .field("round", &self.round)
+            .field("share_generator", &self.share_generator)
+            .field("blind_factor_generator", &self.blind_factor_generator)
+            .field("pedersen_commitments", &self.pedersen_commitments)
+            .field("disqualified", &self.disqualified)
+            .field("qualified", &self.qualified)
+            .field("feldman_verifiers", &self.feldman_verifiers)
+            .field("reconstruct", &self.reconstruct)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, G> GjkrParticipant<F, G>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+{
+    /// Create a new participant with `id` in a `threshold` of `limit` key generation.
+    /// `share_generator` is the generator point to use for shares.
+    /// If [`None`], the default generator is used.
+    /// `blind_factor_generator` is the generator point to use for blinding factor shares.
+    /// If [`None`], [`HashToGroup::blinding_generator`] is used.
+    /// All participants must use the same generators.
+    pub fn new(
+        id: u8,
+        threshold: usize,
+        limit: usize,
+        share_generator: Option<G>,
+        blind_factor_generator: Option<G>,
+    ) -> Result<Self, Error> {
+        Shamir {
+            t: threshold,
+            n: limit,
+        }
+        .check_params()?;
+        if id == 0 || id as usize > limit {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        Ok(Self {
+            id,
+            threshold,
+            limit,
+            round: GjkrRound::One,
+            share_generator: share_generator.unwrap_or_else(G::generator),
+            blind_factor_generator: blind_factor_generator.unwrap_or_else(G::blinding_generator),
+            dealing: None,
+            pedersen_commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            disqualified: BTreeSet::new(),
+            qualified: Vec::new(),
+            feldman_verifiers: BTreeMap::new(),
+            reconstruct: BTreeSet::new(),
+        })
+    }
+
+    /// This participant's identifier
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Deal a random secret. The broadcast is sent to everyone
+    /// and each share is sent privately to its `receiver`.
+    pub fn round1<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<(GjkrRound1Broadcast<G>, Vec<GjkrRound1Share>), Error> {
+        self.check_round(GjkrRound::One)?;
+
+        let secret = F::random(&mut *rng);
+        let dealing = Pedersen {
+            t: self.threshold,
+            n: self.limit,
+        }
+        .split_secret(
+            secret,
+            None,
+            Some(self.share_generator),
+            Some(self.blind_factor_generator),
+            rng,
+        )?;
+
+        let broadcast = GjkrRound1Broadcast {
+            sender: self.id,
+            commitments: dealing.verifier.commitments.clone(),
+        };
+        let mut shares = Vec::with_capacity(self.limit - 1);
+        for (secret_share, blind_share) in dealing
+            .secret_shares
+            .iter()
+            .zip(dealing.blind_shares.iter())
+        {
+            if secret_share.identifier() == self.id {
+                self.shares
+                    .insert(self.id, (secret_share.clone(), blind_share.clone()));
+            } else {
+                shares.push(GjkrRound1Share {
+                    sender: self.id,
+                    receiver: secret_share.identifier(),
+                    secret_share: secret_share.clone(),
+                    blind_share: blind_share.clone(),
+                });
+            }
+        }
+        self.pedersen_commitments
+            .insert(self.id, dealing.verifier.commitments.clone());
+        self.dealing = Some(dealing);
+        self.round = GjkrRound::OneSent;
+        Ok((broadcast, shares))
+    }
+
+    /// Check the round 1 messages from the other dealers.
+    ///
+    /// Dealers without a well formed broadcast are disqualified.
+    /// Complaints are returned for dealers whose shares for this participant
+    /// are missing or don't match their commitments and must be broadcast.
+    pub fn receive_round1(
+        &mut self,
+        broadcasts: &[GjkrRound1Broadcast<G>],
+        shares: &[GjkrRound1Share],
+    ) -> Result<Vec<GjkrComplaint>, Error> {
+        self.check_round(GjkrRound::OneSent)?;
+
+        let mut complaints = Vec::new();
+        for dealer in self.others() {
+            let commitments = match broadcasts.iter().find(|b| b.sender == dealer) {
+                Some(b) if b.commitments.len() == self.threshold => b.commitments.clone(),
+                _ => {
+                    self.disqualified.insert(dealer);
+                    continue;
+                }
+            };
+            self.pedersen_commitments.insert(dealer, commitments);
+
+            let share = shares
+                .iter()
+                .find(|s| s.sender == dealer && s.receiver == self.id);
+            match share {
+                Some(s)
+                    if self.verify_pedersen(dealer, self.id, &s.secret_share, &s.blind_share) =>
+                {
+                    self.shares
+                        .insert(dealer, (s.secret_share.clone(), s.blind_share.clone()));
+                }
+                _ => complaints.push(GjkrComplaint {
+                    accuser: self.id,
+                    accused: dealer,
+                }),
+            }
+        }
+        self.round = GjkrRound::Complaints;
+        Ok(complaints)
+    }
+
+    /// Answer the complaints against this participant by revealing
+    /// the accusers' shares. The justifications must be broadcast.
+    pub fn justify(&self, complaints: &[GjkrComplaint]) -> Result<Vec<GjkrJustification>, Error> {
+        self.check_round(GjkrRound::Complaints)?;
+        let dealing = self.dealing.as_ref().ok_or(Error::InvalidRound)?;
+
+        let mut accusers = BTreeSet::new();
+        for c in complaints.iter().filter(|c| c.accused == self.id) {
+            if c.accuser == 0 || c.accuser as usize > self.limit || c.accuser == self.id {
+                continue;
+            }
+            accusers.insert(c.accuser);
+        }
+        Ok(accusers
+            .into_iter()
+            .map(|accuser| GjkrJustification {
+                dealer: self.id,
+                accuser,
+                secret_share: dealing.secret_shares[accuser as usize - 1].clone(),
+                blind_share: dealing.blind_shares[accuser as usize - 1].clone(),
+            })
+            .collect())
+    }
+
+    /// Determine the qualified set of dealers from all broadcast
+    /// complaints and justifications.
+    ///
+    /// A dealer is disqualified if `threshold` or more participants complained
+    /// or any complaint was not answered with shares matching its commitments.
+    pub fn qualify(
+        &mut self,
+        complaints: &[GjkrComplaint],
+        justifications: &[GjkrJustification],
+    ) -> Result<Vec<u8>, Error> {
+        self.check_round(GjkrRound::Complaints)?;
+
+        for dealer in 1..=self.limit as u8 {
+            if self.disqualified.contains(&dealer) {
+                continue;
+            }
+            let accusers: BTreeSet<u8> = complaints
+                .iter()
+                .filter(|c| {
+                    c.accused == dealer
+                        && c.accuser != dealer
+                        && c.accuser != 0
+                        && c.accuser as usize <= self.limit
+                })
+                .map(|c| c.accuser)
+                .collect();
+            if accusers.len() >= self.threshold {
+                self.disqualified.insert(dealer);
+                continue;
+            }
+            for accuser in accusers {
+                let justification = justifications
+                    .iter()
+                    .find(|j| j.dealer == dealer && j.accuser == accuser);
+                match justification {
+                    Some(j)
+                        if self.verify_pedersen(
+                            dealer,
+                            accuser,
+                            &j.secret_share,
+                            &j.blind_share,
+                        ) =>
+                    {
+                        if accuser == self.id {
+                            self.shares
+                                .insert(dealer, (j.secret_share.clone(), j.blind_share.clone()));
+                        }
+                    }
+                    _ => {
+                        self.disqualified.insert(dealer);
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.qualified = (1..=self.limit as u8)
+            .filter(|i| !self.disqualified.contains(i))
+            .collect();
+        if self.qualified.is_empty() {
+            return Err(Error::SharingMinThreshold);
+        }
+        // Any qualified dealer either sent a valid share or justified it
+        if self.qualified.iter().any(|d| !self.shares.contains_key(d)) {
+            return Err(Error::InvalidShare);
+        }
+        self.round = GjkrRound::Qualified;
+        Ok(self.qualified.clone())
+    }
+
+    /// Reveal the Feldman commitments for this participant's secret.
+    /// The broadcast is sent to everyone.
+    pub fn round2(&self) -> Result<GjkrRound2Broadcast<F, G>, Error> {
+        self.check_round(GjkrRound::Qualified)?;
+        let dealing = self.dealing.as_ref().ok_or(Error::InvalidRound)?;
+        Ok(GjkrRound2Broadcast {
+            sender: self.id,
+            verifier: dealing.verifier.feldman_verifier.clone(),
+        })
+    }
+
+    /// Check the shares from the qualified dealers against their Feldman commitments.
+    ///
+    /// Complaints are returned for dealers whose commitments don't match
+    /// and must be broadcast.
+    pub fn receive_round2(
+        &mut self,
+        broadcasts: &[GjkrRound2Broadcast<F, G>],
+    ) -> Result<Vec<GjkrRound2Complaint>, Error> {
+        self.check_round(GjkrRound::Qualified)?;
+        let own = self.round2()?.verifier;
+
+        let mut complaints = Vec::new();
+        for dealer in self.qualified.clone() {
+            if dealer == self.id {
+                self.feldman_verifiers.insert(dealer, own.clone());
+                continue;
+            }
+            let verifier = match broadcasts.iter().find(|b| b.sender == dealer) {
+                Some(b)
+                    if b.verifier.generator == self.share_generator
+                        && b.verifier.commitments.len() == self.threshold =>
+                {
+                    b.verifier.clone()
+                }
+                _ => {
+                    self.reconstruct.insert(dealer);
+                    continue;
+                }
+            };
+            let (secret_share, blind_share) = &self.shares[&dealer];
+            if !verifier.verify(secret_share) {
+                complaints.push(GjkrRound2Complaint {
+                    accuser: self.id,
+                    accused: dealer,
+                    secret_share: secret_share.clone(),
+                    blind_share: blind_share.clone(),
+                });
+            }
+            self.feldman_verifiers.insert(dealer, verifier);
+        }
+        self.round = GjkrRound::TwoReceived;
+        Ok(complaints)
+    }
+
+    /// Check the broadcast round 2 complaints and reveal this participant's
+    /// shares of every dealer that must be reconstructed.
+    /// The reconstruction shares must be broadcast.
+    pub fn reconstruction_shares(
+        &mut self,
+        complaints: &[GjkrRound2Complaint],
+    ) -> Result<Vec<GjkrReconstructionShare>, Error> {
+        self.check_round(GjkrRound::TwoReceived)?;
+
+        for c in complaints {
+            if !self.qualified.contains(&c.accused) || c.accuser == c.accused {
+                continue;
+            }
+            // A complaint is only valid if the revealed shares are
+            // consistent with the Pedersen commitments
+            if !self.verify_pedersen(c.accused, c.accuser, &c.secret_share, &c.blind_share) {
+                continue;
+            }
+            let consistent = self
+                .feldman_verifiers
+                .get(&c.accused)
+                .map(|v| v.verify(&c.secret_share))
+                .unwrap_or(false);
+            if !consistent {
+                self.reconstruct.insert(c.accused);
+            }
+        }
+
+        self.round = GjkrRound::Reconstructing;
+        Ok(self
+            .reconstruct
+            .iter()
+            .map(|dealer| {
+                let (secret_share, blind_share) = &self.shares[dealer];
+                GjkrReconstructionShare {
+                    sender: self.id,
+                    dealer: *dealer,
+                    secret_share: secret_share.clone(),
+                    blind_share: blind_share.clone(),
+                }
+            })
+            .collect())
+    }
+
+    /// Reconstruct the polynomials of the dealers that were caught cheating
+    /// in round 2 and compute this participant's share of the group secret.
+    pub fn complete(
+        &mut self,
+        reconstruction_shares: &[GjkrReconstructionShare],
+    ) -> Result<GjkrOutput<F, G>, Error> {
+        self.check_round(GjkrRound::Reconstructing)?;
+
+        for dealer in self.reconstruct.clone() {
+            let mut x_coordinates = Vec::with_capacity(self.threshold);
+            let mut y_coordinates = Vec::with_capacity(self.threshold);
+            let mut senders = BTreeSet::new();
+
+            let own = &self.shares[&dealer].0;
+            let own = core::iter::once((self.id, own));
+            let others = reconstruction_shares
+                .iter()
+                .filter(|s| {
+                    s.dealer == dealer
+                        && self.verify_pedersen(dealer, s.sender, &s.secret_share, &s.blind_share)
+                })
+                .map(|s| (s.sender, &s.secret_share));
+            for (sender, share) in own.chain(others) {
+                if x_coordinates.len() == self.threshold {
+                    break;
+                }
+                if !senders.insert(sender) {
+                    continue;
+                }
+                let y = bytes_to_field::<F>(share.value()).ok_or(Error::InvalidShare)?;
+                x_coordinates.push(F::from(sender as u64));
+                y_coordinates.push(y);
+            }
+            if x_coordinates.len() < self.threshold {
+                return Err(Error::SharingMinThreshold);
+            }
+
+            let polynomial = Polynomial {
+                coefficients: Ramp::interpolate(&x_coordinates, &y_coordinates),
+            };
+            let commitments = polynomial
+                .coefficients
+                .iter()
+                .map(|c| self.share_generator * *c)
+                .collect();
+            self.feldman_verifiers.insert(
+                dealer,
+                FeldmanVerifier {
+                    generator: self.share_generator,
+                    commitments,
+                    marker: PhantomData,
+                },
+            );
+            let value = polynomial.evaluate(F::from(self.id as u64), self.threshold);
+            let entry = self.shares.get_mut(&dealer).ok_or(Error::InvalidShare)?;
+            entry.0 = Share::from_field_element(self.id, value)?;
+        }
+
+        let mut verifier: Option<FeldmanVerifier<F, G>> = None;
+        let mut shares = Vec::with_capacity(self.qualified.len());
+        for dealer in &self.qualified {
+            let v = self
+                .feldman_verifiers
+                .get(dealer)
+                .ok_or(Error::InvalidShare)?;
+            verifier = Some(match verifier {
+                None => v.clone(),
+                Some(acc) => (&acc + v)?,
+            });
+            shares.push(self.shares[dealer].0.clone());
+        }
+        let verifier = verifier.ok_or(Error::SharingMinThreshold)?;
+        let share = Share::sum_field_elements::<F>(&shares)?;
+        if !verifier.verify(&share) {
+            return Err(Error::InvalidShare);
+        }
+
+        self.round = GjkrRound::Done;
+        Ok(GjkrOutput {
+            share,
+            public_key: verifier.commitments[0],
+            public_shares: verifier.public_shares(self.limit as u8),
+            qualified: self.qualified.clone(),
+            verifier,
+        })
+    }
+
+    fn others(&self) -> impl Iterator<Item = u8> {
+        let id = self.id;
+        (1..=self.limit as u8).filter(move |i| *i != id)
+    }
+
+    fn check_round(&self, round: GjkrRound) -> Result<(), Error> {
+        if self.round == round {
+            Ok(())
+        } else {
+            Err(Error::InvalidRound)
+        }
+    }
+
+    /// Check the shares `receiver` got from `dealer` against the dealer's Pedersen commitments
+    fn verify_pedersen(
+        &self,
+        dealer: u8,
+        receiver: u8,
+        secret_share: &Share,
+        blind_share: &Share,
+    ) -> bool {
+        if secret_share.identifier() != receiver || blind_share.identifier() != receiver {
+            return false;
+        }
+        let commitments = match self.pedersen_commitments.get(&dealer) {
+            Some(c) => c.clone(),
+            None => return false,
+        };
+        let verifier = PedersenVerifier::<F, G> {
+            generator: self.blind_factor_generator,
+            feldman_verifier: FeldmanVerifier {
+                generator: self.share_generator,
+                commitments: Vec::new(),
+                marker: PhantomData,
+            },
+            commitments,
+        };
+        verifier.verify(secret_share, blind_share)
+    }
+}
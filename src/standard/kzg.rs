@@ -0,0 +1,264 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{deserialize_group_vec, serialize_group, serialize_group_vec, Shamir, Share};
+use crate::lib::*;
+use crate::util::{deserialize_group, hash_to_field};
+use crate::Error;
+use bls12_381_plus::{
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Scalar,
+};
+use elliptic_curve::{ff::Field, group::Group};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+const KZG_DETERMINISTIC_SETUP_DST: &[u8] = b"VSSS-RS-V01-KZG-BLS12381-INSECURE-TEST-SETUP";
+
+/// The structured reference string `g1^{τ^k}` and `g2^{τ^k}` for KZG commitments
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KzgSetup {
+    /// The powers `g1^{τ^k}` for `k` from zero to the maximum threshold minus one
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub g1_powers: Vec<G1Projective>,
+    /// The powers `g2^{τ^k}` for `k` from zero to the maximum threshold minus one
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub g2_powers: Vec<G2Projective>,
+}
+
+impl KzgSetup {
+    /// Generate a setup for thresholds up to `max_threshold` with a random `τ`
+    /// that is erased before returning.
+    ///
+    /// Whoever runs this can forge openings if `τ` leaks,
+    /// so it must be generated by a party everyone trusts.
+    pub fn new<R: RngCore + CryptoRng>(max_threshold: usize, rng: &mut R) -> Result<Self, Error> {
+        Self::from_tau(max_threshold, Scalar::random(rng))
+    }
+
+    /// Derive a setup from `seed`.
+    ///
+    /// `τ` can be recomputed by anyone that knows `seed` so this is only for tests.
+    pub fn deterministic(max_threshold: usize, seed: &[u8]) -> Result<Self, Error> {
+        Self::from_tau(
+            max_threshold,
+            hash_to_field::<Scalar>(&[seed], KZG_DETERMINISTIC_SETUP_DST),
+        )
+    }
+
+    fn from_tau(max_threshold: usize, mut tau: Scalar) -> Result<Self, Error> {
+        if max_threshold < 2 || bool::from(tau.is_zero()) {
+            return Err(Error::SharingMinThreshold);
+        }
+        let mut g1_powers = Vec::with_capacity(max_threshold);
+        let mut g2_powers = Vec::with_capacity(max_threshold);
+        let mut power = <Scalar as Field>::one();
+        for _ in 0..max_threshold {
+            g1_powers.push(<G1Projective as Group>::generator() * power);
+            g2_powers.push(<G2Projective as Group>::generator() * power);
+            power *= tau;
+        }
+        power.zeroize();
+        tau.zeroize();
+        Ok(Self {
+            g1_powers,
+            g2_powers,
+        })
+    }
+
+    /// Commit to the polynomial with `coefficients`
+    fn commit(&self, coefficients: &[Scalar]) -> Result<G1Projective, Error> {
+        self.commit_shifted(coefficients, 0)
+    }
+
+    /// Commit to the polynomial with `coefficients` multiplied by `x^shift`.
+    ///
+    /// The coefficients are secret so this uses constant time scalar
+    /// multiplications instead of [`crate::util::sum_of_products`].
+    fn commit_shifted(&self, coefficients: &[Scalar], shift: usize) -> Result<G1Projective, Error> {
+        if coefficients.len() + shift > self.g1_powers.len() {
+            return Err(Error::SharingLimitLessThanThreshold);
+        }
+        Ok(self.g1_powers[shift..]
+            .iter()
+            .zip(coefficients)
+            .fold(G1Projective::IDENTITY, |acc, (p, c)| acc + p * c))
+    }
+
+    /// The shift `D - t` that lifts a polynomial of degree `t - 1`
+    /// to the largest degree `D - 1` the setup can commit to
+    fn degree_shift(&self, threshold: usize) -> Option<usize> {
+        if threshold < 2
+            || self.g1_powers.len() < threshold
+            || self.g2_powers.len() != self.g1_powers.len()
+        {
+            return None;
+        }
+        Some(self.g1_powers.len() - threshold)
+    }
+}
+
+/// A constant-size opening proof `g1^{q(τ)}` with `q(x) = (f(x) - f(i)) / (x - i)`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KzgProof {
+    /// The identifier of the opened share
+    pub identifier: u8,
+    /// The witness `g1^{q(τ)}`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub witness: G1Projective,
+}
+
+/// A single G1 commitment `g1^{f(τ)}` to the share polynomial `f`
+/// with a proof that `f` has degree less than the threshold `t`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KzgVerifier {
+    /// The threshold necessary for combine
+    pub t: usize,
+    /// The commitment `g1^{f(τ)}`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub commitment: G1Projective,
+    /// The degree proof `g1^{τ^{D-t} f(τ)}` where `D` is the size of the setup.
+    ///
+    /// The setup has no power of `τ` above `D - 1`
+    /// so this only exists if `f` has degree below `t`.
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub degree_proof: G1Projective,
+}
+
+impl KzgVerifier {
+    /// Check `share` is `f(i)` with `e(C - g1^{f(i)}, g2) == e(W, g2^τ - g2^i)`
+    /// and `f` has degree less than `t` with `e(C, g2^{τ^{D-t}}) == e(P, g2)`
+    pub fn verify(&self, share: &Share, proof: &KzgProof, setup: &KzgSetup) -> bool {
+        if share.identifier() == 0 || share.identifier() != proof.identifier {
+            return false;
+        }
+        if !self.verify_degree(setup) {
+            return false;
+        }
+        let value = match share.as_field_element::<Scalar>() {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+        let i = Scalar::from(share.identifier() as u64);
+        // e(C - g1^y + W^i, g2) * e(-W, g2^τ) == 1
+        let lhs =
+            self.commitment - <G1Projective as Group>::generator() * value + proof.witness * i;
+        let terms = [
+            (
+                &G1Affine::from(lhs),
+                &G2Prepared::from(G2Affine::generator()),
+            ),
+            (
+                &G1Affine::from(-proof.witness),
+                &G2Prepared::from(G2Affine::from(setup.g2_powers[1])),
+            ),
+        ];
+        multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity()
+            .into()
+    }
+
+    /// Check the committed polynomial has degree less than `t`
+    pub fn verify_degree(&self, setup: &KzgSetup) -> bool {
+        let shift = match setup.degree_shift(self.t) {
+            Some(shift) => shift,
+            None => return false,
+        };
+        // e(C, g2^{τ^{D-t}}) * e(-P, g2) == 1
+        let terms = [
+            (
+                &G1Affine::from(self.commitment),
+                &G2Prepared::from(G2Affine::from(setup.g2_powers[shift])),
+            ),
+            (
+                &G1Affine::from(-self.degree_proof),
+                &G2Prepared::from(G2Affine::generator()),
+            ),
+        ];
+        multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity()
+            .into()
+    }
+}
+
+/// Verifiable secret sharing with KZG polynomial commitments as described in
+/// <https://www.iacr.org/archive/asiacrypt2010/6477178/6477178.pdf>.
+///
+/// The verifier is a single G1 element regardless of the threshold
+/// and each share comes with a constant-size [`KzgProof`].
+#[derive(Copy, Clone, Debug)]
+pub struct Kzg {
+    /// The threshold necessary for combine
+    pub t: usize,
+    /// The number of shares to allocate
+    pub n: usize,
+}
+
+impl Kzg {
+    /// Create shares, opening proofs and the commitment from a secret
+    pub fn split_secret<R: RngCore + CryptoRng>(
+        &self,
+        secret: Scalar,
+        setup: &KzgSetup,
+        rng: &mut R,
+    ) -> Result<(Vec<Share>, Vec<KzgProof>, KzgVerifier), Error> {
+        let shamir = Shamir {
+            t: self.t,
+            n: self.n,
+        };
+        shamir.check_params()?;
+
+        let shift = setup
+            .degree_shift(self.t)
+            .ok_or(Error::SharingLimitLessThanThreshold)?;
+        let (shares, polynomial) = shamir.get_shares_and_polynomial(secret, rng);
+        let coefficients = &polynomial.coefficients[..self.t];
+        let commitment = setup.commit(coefficients)?;
+        let degree_proof = setup.commit_shifted(coefficients, shift)?;
+
+        let mut proofs = Vec::with_capacity(self.n);
+        for share in &shares {
+            let i = Scalar::from(share.identifier() as u64);
+            // Synthetic division by (x - i), the remainder f(i) is dropped
+            let mut quotient = vec![<Scalar as Field>::zero(); self.t - 1];
+            let mut carry = <Scalar as Field>::zero();
+            for k in (1..self.t).rev() {
+                carry = coefficients[k] + carry * i;
+                quotient[k - 1] = carry;
+            }
+            proofs.push(KzgProof {
+                identifier: share.identifier(),
+                witness: setup.commit(&quotient)?,
+            });
+            quotient.zeroize();
+        }
+        Ok((
+            shares,
+            proofs,
+            KzgVerifier {
+                t: self.t,
+                commitment,
+                degree_proof,
+            },
+        ))
+    }
+}
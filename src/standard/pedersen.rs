@@ -6,8 +6,9 @@
 use super::{
     deserialize_scalar, serialize_scalar, FeldmanVerifier, PedersenVerifier, Shamir, Share,
 };
+use crate::codec::{field_len, ByteReader, ByteWriter};
 use crate::lib::*;
-use crate::Error;
+use crate::{Error, HashToGroup};
 use core::marker::PhantomData;
 use elliptic_curve::{
     ff::PrimeField,
@@ -36,6 +37,71 @@ pub struct PedersenResult<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>
     pub verifier: PedersenVerifier<F, G>,
 }
 
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> PedersenResult<F, G> {
+    /// The length of [`PedersenResult::to_bytes`]
+    pub fn bytes_len(&self) -> usize {
+        let share_len = 1 + field_len::<F>();
+        field_len::<F>() + 1 + 2 * self.secret_shares.len() * share_len + self.verifier.bytes_len()
+    }
+
+    /// The canonical encoding, the blinding factor in the canonical encoding
+    /// of `F`, the number of shares `n` as one byte, the `n` blind shares,
+    /// the `n` secret shares then [`PedersenVerifier::to_bytes`].
+    /// Each share is [`Share::to_bytes`] with a field element value.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0u8; self.bytes_len()];
+        self.to_bytes_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Write [`PedersenResult::to_bytes`] into `out` returning the number of bytes written
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let share_len = 1 + field_len::<F>();
+        if self.blind_shares.len() != self.secret_shares.len()
+            || self
+                .blind_shares
+                .iter()
+                .chain(self.secret_shares.iter())
+                .any(|s| s.0.len() != share_len)
+        {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut writer = ByteWriter::new(out);
+        writer.write_field(&self.blinding)?;
+        writer.write_count(self.secret_shares.len())?;
+        for share in self.blind_shares.iter().chain(self.secret_shares.iter()) {
+            writer.write(&share.0)?;
+        }
+        let mut len = writer.finish();
+        len += self.verifier.to_bytes_into(&mut out[len..])?;
+        Ok(len)
+    }
+
+    /// Decode a result written with [`PedersenResult::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let share_len = 1 + field_len::<F>();
+        let mut reader = ByteReader::new(bytes);
+        let blinding = reader.read_field()?;
+        let n = reader.read_u8()? as usize;
+        let mut shares = Vec::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            let share = Share::from_bytes(reader.read(share_len)?)?;
+            share
+                .as_field_element::<F>()
+                .map_err(|_| Error::InvalidEncoding)?;
+            shares.push(share);
+        }
+        let secret_shares = shares.split_off(n);
+        let verifier = PedersenVerifier::from_bytes(reader.rest())?;
+        Ok(Self {
+            blinding,
+            blind_shares: shares,
+            secret_shares,
+            verifier,
+        })
+    }
+}
+
 /// Pedersen's Verifiable secret sharing scheme.
 /// (see <https://www.cs.cornell.edu/courses/cs754/2001fa/129.PDF>)
 ///
@@ -62,7 +128,8 @@ impl Pedersen {
     /// `share_generator` is the generator point to use for shares.
     /// If [`None`], the default generator is used.
     /// `blind_factor_generator` is the generator point to use for blinding factor shares.
-    /// If [`None`], a random generator is used
+    /// If [`None`], [`HashToGroup::blinding_generator`] is used so nobody
+    /// knows its discrete log with respect to the share generator.
     pub fn split_secret<F, G, R>(
         &self,
         secret: F,
@@ -73,7 +140,7 @@ impl Pedersen {
     ) -> Result<PedersenResult<F, G>, Error>
     where
         F: PrimeField,
-        G: Group + GroupEncoding + Default + ScalarMul<F>,
+        G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
         R: RngCore + CryptoRng,
     {
         let shamir = Shamir {
@@ -87,8 +154,7 @@ impl Pedersen {
         let mut crng = ChaChaRng::from_seed(seed);
 
         let g = share_generator.unwrap_or_else(G::generator);
-        let t = F::random(&mut crng);
-        let h = blind_factor_generator.unwrap_or_else(|| G::generator() * t);
+        let h = blind_factor_generator.unwrap_or_else(G::blinding_generator);
 
         let blinding = blinding.unwrap_or_else(|| F::random(&mut crng));
         let (secret_shares, secret_polynomial) =
@@ -0,0 +1,294 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{
+    deserialize_group_vec, serialize_group, serialize_group_vec, DleqProof, Shamir, Share,
+};
+use crate::lib::*;
+use crate::util::{deserialize_group, sum_of_products};
+use crate::{Error, HashToGroup};
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// The domain separation tag used to derive the generator for share commitments
+pub const PVSS_COMMITMENT_GENERATOR_DST: &[u8] = b"VSSS-RS-V01-PVSS-COMMITMENT-GENERATOR";
+
+/// A publicly verifiable dealing.
+///
+/// Recipient `i` has the public key `y_i = g^{x_i}`. The dealer publishes
+/// `v_i = h^{s_i}` and the encrypted share `Y_i = y_i^{s_i}` with a proof
+/// they use the same `s_i`, where `h` is a generator with no known
+/// discrete log with respect to `g`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PvssDealing<F: PrimeField, G: Group + GroupEncoding> {
+    /// The share commitments `v_i = h^{s_i}`
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub commitments: Vec<G>,
+    /// The encrypted shares `Y_i = y_i^{s_i}`
+    #[serde(
+        serialize_with = "serialize_group_vec",
+        deserialize_with = "deserialize_group_vec"
+    )]
+    pub encrypted_shares: Vec<G>,
+    /// The proofs `log_h(v_i) == log_{y_i}(Y_i)`
+    #[serde(bound(serialize = "DleqProof<F>: Serialize"))]
+    #[serde(bound(deserialize = "DleqProof<F>: Deserialize<'de>"))]
+    pub proofs: Vec<DleqProof<F>>,
+}
+
+/// A recipient's decrypted share `g^{s_i}` with a proof of correct decryption
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct PvssDecryptedShare<F: PrimeField, G: Group + GroupEncoding> {
+    /// The recipient's identifier
+    pub identifier: u8,
+    /// The decrypted share `g^{s_i}`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub value: G,
+    /// The proof `log_g(y_i) == log_{g^{s_i}}(Y_i)`
+    #[serde(bound(serialize = "DleqProof<F>: Serialize"))]
+    #[serde(bound(deserialize = "DleqProof<F>: Deserialize<'de>"))]
+    pub proof: DleqProof<F>,
+}
+
+/// Publicly verifiable secret sharing as described in
+/// <https://www.win.tue.nl/~berry/papers/crypto99.pdf> with the
+/// O(n) dual code check from <https://eprint.iacr.org/2017/216.pdf>.
+///
+/// Anyone can check a dealing with only the recipients' public keys.
+/// The reconstructed secret is the group element `g^s`.
+/// `public_keys[i - 1]` is the key of the recipient with identifier `i`.
+#[derive(Copy, Clone, Debug)]
+pub struct Pvss {
+    /// The threshold
+    pub t: usize,
+    /// The total number of recipients
+    pub n: usize,
+}
+
+impl Pvss {
+    /// Share `secret` by encrypting each share to its recipient's public key
+    pub fn deal<F, G, R>(
+        &self,
+        secret: F,
+        public_keys: &[G],
+        rng: &mut R,
+    ) -> Result<PvssDealing<F, G>, Error>
+    where
+        F: PrimeField,
+        G: HashToGroup + Default + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        self.check_public_keys(public_keys)?;
+        let h = commitment_generator::<G>()?;
+        let shares = Shamir {
+            t: self.t,
+            n: self.n,
+        }
+        .split_secret::<F, R>(secret, rng)?;
+
+        let mut dealing = PvssDealing {
+            commitments: Vec::with_capacity(self.n),
+            encrypted_shares: Vec::with_capacity(self.n),
+            proofs: Vec::with_capacity(self.n),
+        };
+        for (share, public_key) in shares.iter().zip(public_keys) {
+            let s = share.as_field_element::<F>()?;
+            dealing.commitments.push(h * s);
+            dealing.encrypted_shares.push(*public_key * s);
+            dealing.proofs.push(DleqProof::new(
+                s,
+                h,
+                *public_key,
+                &[share.identifier()],
+                &mut *rng,
+            ));
+        }
+        Ok(dealing)
+    }
+
+    /// Check every encrypted share is consistent with its commitment
+    /// and the commitments lie on a polynomial of degree less than the threshold.
+    ///
+    /// `rng` picks the random dual codeword and must not be controlled by the dealer.
+    pub fn verify_dealing<F, G, R>(
+        &self,
+        dealing: &PvssDealing<F, G>,
+        public_keys: &[G],
+        rng: &mut R,
+    ) -> Result<(), Error>
+    where
+        F: PrimeField,
+        G: HashToGroup + Default + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        self.check_public_keys(public_keys)?;
+        if dealing.commitments.len() != self.n
+            || dealing.encrypted_shares.len() != self.n
+            || dealing.proofs.len() != self.n
+        {
+            return Err(Error::InvalidShare);
+        }
+        let h = commitment_generator::<G>()?;
+        for (i, (((proof, commitment), public_key), encrypted)) in dealing
+            .proofs
+            .iter()
+            .zip(&dealing.commitments)
+            .zip(public_keys)
+            .zip(&dealing.encrypted_shares)
+            .enumerate()
+        {
+            if !proof.verify(h, *commitment, *public_key, *encrypted, &[i as u8 + 1]) {
+                return Err(Error::InvalidProof);
+            }
+        }
+
+        // SCRAPE: Σ v_i^{c_i} == 1 for a random codeword c of the dual code
+        // c_i = w_i * f(i) with w_i = Π_{j != i} 1 / (i - j) and deg(f) < n - t
+        let degree = self.n - self.t;
+        if degree == 0 {
+            return Ok(());
+        }
+        let f: Vec<F> = (0..degree).map(|_| F::random(&mut *rng)).collect();
+        let mut codeword = Vec::with_capacity(self.n);
+        for i in 1..=self.n {
+            let x = F::from(i as u64);
+            let mut w = F::one();
+            for j in (1..=self.n).filter(|j| *j != i) {
+                w *= x - F::from(j as u64);
+            }
+            let w = Option::<F>::from(w.invert()).ok_or(Error::InvalidShare)?;
+            let mut fx = F::zero();
+            for c in f.iter().rev() {
+                fx = fx * x + c;
+            }
+            codeword.push(w * fx);
+        }
+        if bool::from(sum_of_products(&dealing.commitments, &codeword).is_identity()) {
+            Ok(())
+        } else {
+            Err(Error::InvalidShare)
+        }
+    }
+
+    /// Decrypt the share for `identifier` with the recipient's secret key
+    /// and prove it was decrypted correctly
+    pub fn decrypt_share<F, G, R>(
+        identifier: u8,
+        secret_key: F,
+        dealing: &PvssDealing<F, G>,
+        rng: &mut R,
+    ) -> Result<PvssDecryptedShare<F, G>, Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let encrypted = encrypted_share(identifier, dealing)?;
+        let inverse = Option::<F>::from(secret_key.invert()).ok_or(Error::InvalidSecret)?;
+        let value = encrypted * inverse;
+        Ok(PvssDecryptedShare {
+            identifier,
+            value,
+            proof: DleqProof::new(secret_key, G::generator(), value, &[identifier], rng),
+        })
+    }
+
+    /// Check a decrypted share against the recipient's public key
+    pub fn verify_decrypted_share<F, G>(
+        share: &PvssDecryptedShare<F, G>,
+        public_key: &G,
+        dealing: &PvssDealing<F, G>,
+    ) -> Result<(), Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + ScalarMul<F>,
+    {
+        let encrypted = encrypted_share(share.identifier, dealing)?;
+        if share.proof.verify(
+            G::generator(),
+            *public_key,
+            share.value,
+            encrypted,
+            &[share.identifier],
+        ) {
+            Ok(())
+        } else {
+            Err(Error::InvalidProof)
+        }
+    }
+
+    /// Check at least threshold decrypted shares and interpolate the secret `g^s`.
+    ///
+    /// Returns [`Error::InvalidProof`] if any decrypted share is invalid.
+    /// Use [`Pvss::verify_decrypted_share`] to identify the culprits.
+    pub fn combine<F, G>(
+        &self,
+        shares: &[PvssDecryptedShare<F, G>],
+        dealing: &PvssDealing<F, G>,
+        public_keys: &[G],
+    ) -> Result<G, Error>
+    where
+        F: PrimeField,
+        G: Group + GroupEncoding + Default + ScalarMul<F>,
+    {
+        self.check_public_keys(public_keys)?;
+        if shares.len() < self.t {
+            return Err(Error::SharingMinThreshold);
+        }
+        let mut partials = Vec::with_capacity(shares.len());
+        for share in shares {
+            let public_key = public_keys
+                .get((share.identifier as usize).wrapping_sub(1))
+                .ok_or(Error::SharingInvalidIdentifier)?;
+            Self::verify_decrypted_share(share, public_key, dealing)?;
+            partials.push(Share::from_group_element(share.identifier, share.value)?);
+        }
+        Shamir {
+            t: self.t,
+            n: self.n,
+        }
+        .combine_shares_group::<F, G>(&partials)
+    }
+
+    fn check_public_keys<G: Group>(&self, public_keys: &[G]) -> Result<(), Error> {
+        Shamir {
+            t: self.t,
+            n: self.n,
+        }
+        .check_params()?;
+        if public_keys.len() != self.n {
+            return Err(Error::InvalidShare);
+        }
+        if public_keys.iter().any(|k| bool::from(k.is_identity())) {
+            return Err(Error::InvalidGenerator);
+        }
+        Ok(())
+    }
+}
+
+fn commitment_generator<G: HashToGroup>() -> Result<G, Error> {
+    G::hash_to_group(&[], PVSS_COMMITMENT_GENERATOR_DST)
+}
+
+fn encrypted_share<F: PrimeField, G: Group + GroupEncoding>(
+    identifier: u8,
+    dealing: &PvssDealing<F, G>,
+) -> Result<G, Error> {
+    dealing
+        .encrypted_shares
+        .get((identifier as usize).wrapping_sub(1))
+        .copied()
+        .ok_or(Error::SharingInvalidIdentifier)
+}
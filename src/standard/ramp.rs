@@ -0,0 +1,159 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use super::{Polynomial, Share};
+use crate::lib::*;
+use crate::{bytes_to_field, Error};
+use elliptic_curve::ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+
+/// Ramp (non-perfect) secret sharing scheme.
+///
+/// `t` is the privacy threshold. Any `t` or fewer shares reveal nothing about the secret.
+/// `r` is the reconstruction threshold. Any `r` shares reconstruct the secret.
+/// Sets of shares in between leak partial information.
+///
+/// The secret is `r - t` field elements long while each share is still a single
+/// field element, so storage overhead shrinks as the gap between thresholds grows.
+/// With `r - t = 1` this is the same as Shamir with threshold `r`.
+///
+/// The secret is placed in the low coefficients of a degree `r - 1`
+/// polynomial and the remaining `t` coefficients are random.
+#[derive(Copy, Clone, Debug)]
+pub struct Ramp {
+    /// The privacy threshold
+    pub t: usize,
+    /// The reconstruction threshold
+    pub r: usize,
+    /// The number of shares to allocate
+    pub n: usize,
+}
+
+impl Ramp {
+    /// Create shares from a secret of `r - t` field elements.
+    /// F is the prime field
+    pub fn split_secret<F, R>(&self, secret: &[F], rng: &mut R) -> Result<Vec<Share>, Error>
+    where
+        F: PrimeField,
+        R: RngCore + CryptoRng,
+    {
+        self.check_params()?;
+        if secret.len() != self.secret_len() {
+            return Err(Error::InvalidSecret);
+        }
+
+        let mut coefficients = Vec::with_capacity(self.r);
+        coefficients.extend_from_slice(secret);
+        for _ in 0..self.t {
+            coefficients.push(F::random(&mut *rng));
+        }
+        let polynomial = Polynomial { coefficients };
+
+        let mut shares = Vec::with_capacity(self.n);
+        let mut x = F::one();
+        for i in 0..self.n {
+            let y = polynomial.evaluate(x, self.r);
+            shares.push(Share::from_field_element((i + 1) as u8, y)?);
+            x += F::one();
+        }
+        Ok(shares)
+    }
+
+    /// Reconstruct a secret from at least `r` shares created from `split_secret`.
+    pub fn combine_shares<F>(&self, shares: &[Share]) -> Result<Vec<F>, Error>
+    where
+        F: PrimeField,
+    {
+        self.check_params()?;
+
+        if shares.len() < self.r {
+            return Err(Error::SharingMinThreshold);
+        }
+
+        let mut dups = BTreeSet::new();
+        let mut x_coordinates = Vec::with_capacity(self.r);
+        let mut y_coordinates = Vec::with_capacity(self.r);
+        for s in shares.iter().take(self.r) {
+            let identifier = s.identifier();
+            if identifier == 0 {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            if !dups.insert(identifier) {
+                return Err(Error::SharingDuplicateIdentifier);
+            }
+            let y = bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+            x_coordinates.push(F::from(identifier as u64));
+            y_coordinates.push(y);
+        }
+
+        let mut coefficients = Self::interpolate(&x_coordinates, &y_coordinates);
+        coefficients.truncate(self.secret_len());
+        Ok(coefficients)
+    }
+
+    /// The number of field elements in the secret
+    pub fn secret_len(&self) -> usize {
+        self.r.saturating_sub(self.t)
+    }
+
+    /// Recover all coefficients of the polynomial through the points.
+    pub(crate) fn interpolate<F: PrimeField>(x_coordinates: &[F], y_coordinates: &[F]) -> Vec<F> {
+        let limit = x_coordinates.len();
+
+        // The monic polynomial (x - x_0)(x - x_1)...(x - x_{r-1})
+        // without its leading coefficient
+        let mut master = Vec::with_capacity(limit);
+        master.resize(limit, F::zero());
+        for (d, x) in x_coordinates.iter().enumerate() {
+            // multiply by (x - x_d) where the implicit leading one is at position d
+            master[d] = if d == 0 { -*x } else { master[d - 1] - *x };
+            for k in (1..d).rev() {
+                master[k] = master[k - 1] - master[k] * x;
+            }
+            if d > 0 {
+                master[0] = -master[0] * x;
+            }
+        }
+
+        let mut coefficients = Vec::with_capacity(limit);
+        coefficients.resize(limit, F::zero());
+        let mut basis = Vec::with_capacity(limit);
+        basis.resize(limit, F::zero());
+        for i in 0..limit {
+            // master / (x - x_i) by synthetic division
+            basis[limit - 1] = F::one();
+            for k in (1..limit).rev() {
+                basis[k - 1] = master[k] + x_coordinates[i] * basis[k];
+            }
+
+            let mut denom = F::one();
+            for j in 0..limit {
+                if i != j {
+                    denom *= x_coordinates[i] - x_coordinates[j];
+                }
+            }
+            let scale = y_coordinates[i] * denom.invert().unwrap();
+            for (c, b) in coefficients.iter_mut().zip(basis.iter()) {
+                *c += *b * scale;
+            }
+        }
+        coefficients
+    }
+
+    pub(crate) fn check_params(&self) -> Result<(), Error> {
+        if self.t < 1 {
+            return Err(Error::SharingMinThreshold);
+        }
+        if self.r <= self.t {
+            return Err(Error::SharingMinThreshold);
+        }
+        if self.n < self.r {
+            return Err(Error::SharingLimitLessThanThreshold);
+        }
+        if self.n > 255 {
+            return Err(Error::SharingMaxRequest);
+        }
+        Ok(())
+    }
+}
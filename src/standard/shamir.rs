@@ -4,7 +4,7 @@
 */
 use super::{Polynomial, Share};
 use crate::lib::*;
-use crate::util::bytes_to_group;
+use crate::util::{bytes_to_group, sum_of_products};
 use crate::{bytes_to_field, Error};
 use core::ops::{AddAssign, Mul};
 use elliptic_curve::{
@@ -46,7 +46,8 @@ impl Shamir {
     where
         F: PrimeField,
     {
-        self.combine::<F, F>(shares, bytes_to_field)
+        let (x_coordinates, y_coordinates) = self.coordinates::<F, F>(shares, bytes_to_field)?;
+        Ok(Self::interpolate(&x_coordinates, &y_coordinates))
     }
 
     /// Reconstruct a secret from shares created from `split_secret`.
@@ -60,13 +61,20 @@ impl Shamir {
         F: PrimeField,
         G: Group + GroupEncoding + ScalarMul<F> + Default,
     {
-        self.combine::<F, G>(shares, bytes_to_group)
+        let (x_coordinates, y_coordinates) = self.coordinates::<F, G>(shares, bytes_to_group)?;
+        // The shares and lagrange coefficients are public so
+        // use a multi-scalar multiplication
+        let basis = Self::lagrange_basis(&x_coordinates);
+        Ok(sum_of_products(&y_coordinates, &basis))
     }
 
-    fn combine<F, S>(&self, shares: &[Share], f: fn(&[u8]) -> Option<S>) -> Result<S, Error>
+    fn coordinates<F, S>(
+        &self,
+        shares: &[Share],
+        f: fn(&[u8]) -> Option<S>,
+    ) -> Result<(Vec<F>, Vec<S>), Error>
     where
         F: PrimeField,
-        S: Default + Copy + AddAssign + Mul<F, Output = S>,
     {
         self.check_params()?;
 
@@ -98,8 +106,7 @@ impl Shamir {
             x_coordinates.push(F::from(identifier as u64));
             y_coordinates.push(y.unwrap());
         }
-        let secret = Self::interpolate(&x_coordinates, &y_coordinates);
-        Ok(secret)
+        Ok((x_coordinates, y_coordinates))
     }
 
     pub(crate) fn get_shares_and_polynomial<F, R>(
@@ -135,10 +142,23 @@ impl Shamir {
         F: PrimeField,
         S: Default + Copy + AddAssign + Mul<F, Output = S>,
     {
-        let limit = x_coordinates.len();
         // Initialize to zero
         let mut result = S::default();
 
+        for (y, basis) in y_coordinates
+            .iter()
+            .zip(Self::lagrange_basis(x_coordinates))
+        {
+            result += *y * basis;
+        }
+        result
+    }
+
+    /// Calculate the lagrange basis polynomials at zero
+    fn lagrange_basis<F: PrimeField>(x_coordinates: &[F]) -> Vec<F> {
+        let limit = x_coordinates.len();
+        let mut bases = Vec::with_capacity(limit);
+
         for i in 0..limit {
             let mut basis = F::one();
             for j in 0..limit {
@@ -151,10 +171,9 @@ impl Shamir {
                 // x_m / (x_m - x_j) * ...
                 basis *= x_coordinates[j] * denom;
             }
-
-            result += y_coordinates[i] * basis;
+            bases.push(basis);
         }
-        result
+        bases
     }
 
     pub(crate) fn check_params(&self) -> Result<(), Error> {
@@ -4,7 +4,7 @@
 */
 
 use crate::lib::*;
-use crate::Error;
+use crate::{codec::ByteWriter, util::bytes_to_field, Error};
 use core::{
     array::TryFromSliceError,
     convert::TryFrom,
@@ -106,6 +106,41 @@ impl From<Share> for Vec<u8> {
 }
 
 impl Share {
+    /// Create a share from an identifier and a prime field element
+    pub fn from_field_element<F: PrimeField>(identifier: u8, value: F) -> Result<Self, Error> {
+        Self::from_parts(identifier, value.to_repr().as_ref())
+    }
+
+    /// Create a share from an identifier and a group element
+    pub fn from_group_element<G: GroupEncoding>(identifier: u8, value: G) -> Result<Self, Error> {
+        Self::from_parts(identifier, value.to_bytes().as_ref())
+    }
+
+    fn from_parts(identifier: u8, value: &[u8]) -> Result<Self, Error> {
+        if identifier == 0 {
+            return Err(Error::SharingInvalidIdentifier);
+        }
+        let mut bytes = Vec::with_capacity(1 + value.len());
+        bytes.push(identifier);
+        bytes.extend_from_slice(value);
+        Ok(Self(bytes))
+    }
+
+    /// Sum the shares with the same identifier that a participant
+    /// received from several dealers into a single share.
+    pub fn sum_field_elements<F: PrimeField>(shares: &[Self]) -> Result<Self, Error> {
+        let first = shares.first().ok_or(Error::InvalidShare)?;
+        let identifier = first.identifier();
+        let mut value = F::zero();
+        for s in shares {
+            if s.identifier() != identifier {
+                return Err(Error::SharingInvalidIdentifier);
+            }
+            value += bytes_to_field::<F>(s.value()).ok_or(Error::InvalidShare)?;
+        }
+        Self::from_field_element(identifier, value)
+    }
+
     /// True if all value bytes are zero in constant time
     pub fn is_zero(&self) -> bool {
         let mut v = 0u8;
@@ -125,6 +160,32 @@ impl Share {
         &self.0[1..]
     }
 
+    /// The length of [`Share::to_bytes`]
+    pub fn bytes_len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The canonical encoding, the identifier byte followed by the value
+    /// in the canonical encoding of its field or group element.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    /// Write [`Share::to_bytes`] into `out` returning the number of bytes written
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let mut writer = ByteWriter::new(out);
+        writer.write(&self.0)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a share written with [`Share::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 2 || bytes[0] == 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+
     /// Convert this share into a group element
     pub fn as_group_element<G: GroupEncoding>(&self) -> Result<G, Error> {
         let mut repr = G::Repr::default();
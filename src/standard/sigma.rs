@@ -0,0 +1,179 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{deserialize_scalar, serialize_scalar};
+use crate::lib::*;
+use crate::util::hash_to_field;
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+const SCHNORR_PROOF_DST: &[u8] = b"VSSS-RS-V01-SCHNORR-DLOG-PROOF";
+const DLEQ_PROOF_DST: &[u8] = b"VSSS-RS-V01-CHAUM-PEDERSEN-DLEQ-PROOF";
+const OKAMOTO_PROOF_DST: &[u8] = b"VSSS-RS-V01-OKAMOTO-PEDERSEN-OPENING-PROOF";
+
+/// A Schnorr proof of knowledge of `x` such that `h = g^x`,
+/// made non-interactive with Fiat-Shamir
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct SchnorrProof<F: PrimeField> {
+    /// The challenge `c`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub challenge: F,
+    /// The response `z = k - c * x`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub response: F,
+}
+
+impl<F: PrimeField> SchnorrProof<F> {
+    /// Prove knowledge of the discrete log of `g^secret` bound to `context`
+    pub fn new<G, R>(secret: F, g: G, context: &[u8], rng: &mut R) -> Self
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let k = F::random(rng);
+        let challenge = challenge::<F, G>(SCHNORR_PROOF_DST, context, &[g, g * secret, g * k]);
+        Self {
+            challenge,
+            response: k - challenge * secret,
+        }
+    }
+
+    /// Check the prover knows `x` such that `h = g^x` for `context`
+    pub fn verify<G>(&self, g: G, h: G, context: &[u8]) -> bool
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+    {
+        // a = g^z * h^c
+        let a = g * self.response + h * self.challenge;
+        challenge::<F, G>(SCHNORR_PROOF_DST, context, &[g, h, a]) == self.challenge
+    }
+}
+
+/// A Chaum-Pedersen proof that `h1 = g1^x` and `h2 = g2^x`
+/// for the same secret `x`, made non-interactive with Fiat-Shamir
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct DleqProof<F: PrimeField> {
+    /// The challenge `c`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub challenge: F,
+    /// The response `z = k - c * x`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub response: F,
+}
+
+impl<F: PrimeField> DleqProof<F> {
+    /// Prove `g1^secret` and `g2^secret` have the same discrete log bound to `context`
+    pub fn new<G, R>(secret: F, g1: G, g2: G, context: &[u8], rng: &mut R) -> Self
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let k = F::random(rng);
+        let challenge = challenge::<F, G>(
+            DLEQ_PROOF_DST,
+            context,
+            &[g1, g1 * secret, g2, g2 * secret, g1 * k, g2 * k],
+        );
+        Self {
+            challenge,
+            response: k - challenge * secret,
+        }
+    }
+
+    /// Check `h1 = g1^x` and `h2 = g2^x` for the same `x` and `context`
+    pub fn verify<G>(&self, g1: G, h1: G, g2: G, h2: G, context: &[u8]) -> bool
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+    {
+        // a1 = g1^z * h1^c, a2 = g2^z * h2^c
+        let a1 = g1 * self.response + h1 * self.challenge;
+        let a2 = g2 * self.response + h2 * self.challenge;
+        challenge::<F, G>(DLEQ_PROOF_DST, context, &[g1, h1, g2, h2, a1, a2]) == self.challenge
+    }
+}
+
+/// An Okamoto proof of knowledge of the opening `(m, r)` of a
+/// Pedersen commitment `C = g^m * h^r`, made non-interactive with Fiat-Shamir
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct OkamotoProof<F: PrimeField> {
+    /// The challenge `c`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub challenge: F,
+    /// The response `z1 = k1 - c * m`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub response: F,
+    /// The response `z2 = k2 - c * r`
+    #[serde(
+        serialize_with = "serialize_scalar",
+        deserialize_with = "deserialize_scalar"
+    )]
+    pub blind_response: F,
+}
+
+impl<F: PrimeField> OkamotoProof<F> {
+    /// Prove knowledge of the opening of `g^secret * h^blind` bound to `context`
+    pub fn new<G, R>(secret: F, blind: F, g: G, h: G, context: &[u8], rng: &mut R) -> Self
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        let k1 = F::random(&mut *rng);
+        let k2 = F::random(rng);
+        let commitment = g * secret + h * blind;
+        let challenge = challenge::<F, G>(
+            OKAMOTO_PROOF_DST,
+            context,
+            &[g, h, commitment, g * k1 + h * k2],
+        );
+        Self {
+            challenge,
+            response: k1 - challenge * secret,
+            blind_response: k2 - challenge * blind,
+        }
+    }
+
+    /// Check the prover knows `(m, r)` such that `commitment = g^m * h^r` for `context`
+    pub fn verify<G>(&self, g: G, h: G, commitment: G, context: &[u8]) -> bool
+    where
+        G: Group + GroupEncoding + ScalarMul<F>,
+    {
+        // a = g^z1 * h^z2 * C^c
+        let a = g * self.response + h * self.blind_response + commitment * self.challenge;
+        challenge::<F, G>(OKAMOTO_PROOF_DST, context, &[g, h, commitment, a]) == self.challenge
+    }
+}
+
+/// c = H(len(context) || context || points)
+fn challenge<F: PrimeField, G: GroupEncoding>(dst: &[u8], context: &[u8], points: &[G]) -> F {
+    let context_len = (context.len() as u64).to_be_bytes();
+    let encoded: Vec<G::Repr> = points.iter().map(|p| p.to_bytes()).collect();
+    let mut msgs: Vec<&[u8]> = Vec::with_capacity(points.len() + 2);
+    msgs.push(&context_len);
+    msgs.push(context);
+    msgs.extend(encoded.iter().map(|p| p.as_ref()));
+    hash_to_field::<F>(&msgs, dst)
+}
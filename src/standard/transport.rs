@@ -0,0 +1,276 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+
+use super::{deserialize_byte_vec, serialize_byte_vec, serialize_group, Share};
+use crate::lib::*;
+use crate::util::deserialize_group;
+use crate::Error;
+use chacha20poly1305::{
+    aead::{AeadInPlace, KeyInit},
+    ChaCha20Poly1305, Key, Nonce, Tag,
+};
+use elliptic_curve::{
+    ff::PrimeField,
+    group::{Group, GroupEncoding, ScalarMul},
+};
+use hkdf::Hkdf;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2_10::Sha256;
+use zeroize::Zeroize;
+
+const SHARE_TRANSPORT_DST: &[u8] =
+    b"VSSS-RS-V01-SHARE-TRANSPORT-ECIES-HKDF-SHA256-CHACHA20POLY1305";
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+
+/// A share encrypted to a recipient's public key `g^x` with ECIES from
+/// <https://www.secg.org/sec1-v2.pdf> section 5.1 over the sharing group.
+///
+/// The key is agreed with ECDH against a fresh ephemeral key and expanded
+/// with HKDF-SHA256 into a key and nonce for ChaCha20-Poly1305 from
+/// <https://www.rfc-editor.org/rfc/rfc8439>.
+/// The associated data, typically the dealer's
+/// [`super::FeldmanVerifier::hash`] or [`super::PedersenVerifier::hash`],
+/// and the identifier are authenticated by the AEAD
+/// so a share can't be replayed with another dealing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedShare<G: Group + GroupEncoding> {
+    /// The identifier of the encrypted share
+    pub identifier: u8,
+    /// The ephemeral public key `g^r`
+    #[serde(
+        serialize_with = "serialize_group",
+        deserialize_with = "deserialize_group"
+    )]
+    pub ephemeral_key: G,
+    /// The encrypted share followed by the authentication tag
+    #[serde(
+        serialize_with = "serialize_byte_vec",
+        deserialize_with = "deserialize_byte_vec"
+    )]
+    pub ciphertext: Vec<u8>,
+}
+
+impl<G: Group + GroupEncoding> EncryptedShare<G> {
+    /// Encrypt `share` to `recipient` binding `associated_data`
+    pub fn encrypt<F, R>(
+        share: &Share,
+        recipient: &G,
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        Self::seal::<F, R>(
+            share.identifier(),
+            &share.0,
+            recipient,
+            associated_data,
+            rng,
+        )
+    }
+
+    /// Encrypt a Pedersen share and its blind share together
+    /// to `recipient` binding `associated_data`
+    pub fn encrypt_pedersen<F, R>(
+        share: &Share,
+        blind_share: &Share,
+        recipient: &G,
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        if share.identifier() != blind_share.identifier() || share.0.len() != blind_share.0.len() {
+            return Err(Error::InvalidShare);
+        }
+        let mut plaintext = Vec::with_capacity(share.0.len() * 2);
+        plaintext.extend_from_slice(&share.0);
+        plaintext.extend_from_slice(&blind_share.0);
+        let res = Self::seal::<F, R>(
+            share.identifier(),
+            &plaintext,
+            recipient,
+            associated_data,
+            rng,
+        );
+        plaintext.zeroize();
+        res
+    }
+
+    /// Decrypt the share with the recipient's secret key.
+    ///
+    /// Returns [`Error::InvalidCiphertext`] if the ciphertext or
+    /// associated data was modified or the key is wrong.
+    pub fn decrypt<F>(&self, secret_key: &F, associated_data: &[u8]) -> Result<Share, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+    {
+        self.plaintext_share(self.open(secret_key, associated_data)?)
+    }
+
+    /// Decrypt a Pedersen share and its blind share with the recipient's secret key
+    pub fn decrypt_pedersen<F>(
+        &self,
+        secret_key: &F,
+        associated_data: &[u8],
+    ) -> Result<(Share, Share), Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+    {
+        self.plaintext_pedersen_shares(self.open(secret_key, associated_data)?)
+    }
+
+    pub(crate) fn plaintext_share(&self, plaintext: Vec<u8>) -> Result<Share, Error> {
+        let share = Share(plaintext);
+        if share.identifier() != self.identifier {
+            return Err(Error::InvalidCiphertext);
+        }
+        Ok(share)
+    }
+
+    pub(crate) fn plaintext_pedersen_shares(
+        &self,
+        mut plaintext: Vec<u8>,
+    ) -> Result<(Share, Share), Error> {
+        if plaintext.len() % 2 != 0 {
+            plaintext.zeroize();
+            return Err(Error::InvalidCiphertext);
+        }
+        let blind_share = Share(plaintext.split_off(plaintext.len() / 2));
+        let share = Share(plaintext);
+        if share.identifier() != self.identifier || blind_share.identifier() != self.identifier {
+            return Err(Error::InvalidCiphertext);
+        }
+        Ok((share, blind_share))
+    }
+
+    fn seal<F, R>(
+        identifier: u8,
+        plaintext: &[u8],
+        recipient: &G,
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+        R: RngCore + CryptoRng,
+    {
+        if plaintext.is_empty() {
+            return Err(Error::InvalidShare);
+        }
+        if recipient.is_identity().into() {
+            return Err(Error::InvalidGenerator);
+        }
+        let r = F::random(rng);
+        let ephemeral_key = G::generator() * r;
+        let (mut key, nonce) = derive_keys(&(*recipient * r), &ephemeral_key, recipient)?;
+        let mut ciphertext = Vec::with_capacity(plaintext.len() + TAG_SIZE);
+        ciphertext.extend_from_slice(plaintext);
+        let tag = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt_in_place_detached(
+                Nonce::from_slice(&nonce),
+                &aead_associated_data(identifier, associated_data),
+                &mut ciphertext,
+            )
+            .map_err(|_| Error::InvalidShare);
+        key.zeroize();
+        ciphertext.extend_from_slice(&tag?);
+        Ok(Self {
+            identifier,
+            ephemeral_key,
+            ciphertext,
+        })
+    }
+
+    fn open<F>(&self, secret_key: &F, associated_data: &[u8]) -> Result<Vec<u8>, Error>
+    where
+        F: PrimeField,
+        G: ScalarMul<F>,
+    {
+        let recipient = G::generator() * *secret_key;
+        self.open_shared(
+            &(self.ephemeral_key * *secret_key),
+            &recipient,
+            associated_data,
+        )
+    }
+
+    /// Decrypt with the ECDH shared point `g^{rx}` instead of the secret key
+    pub(crate) fn open_shared(
+        &self,
+        shared: &G,
+        recipient: &G,
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        if self.ciphertext.len() <= TAG_SIZE || bool::from(self.ephemeral_key.is_identity()) {
+            return Err(Error::InvalidCiphertext);
+        }
+        let (ciphertext, tag) = self.ciphertext.split_at(self.ciphertext.len() - TAG_SIZE);
+        let (mut key, nonce) = derive_keys(shared, &self.ephemeral_key, recipient)?;
+        let mut plaintext = ciphertext.to_vec();
+        let res = ChaCha20Poly1305::new(Key::from_slice(&key)).decrypt_in_place_detached(
+            Nonce::from_slice(&nonce),
+            &aead_associated_data(self.identifier, associated_data),
+            &mut plaintext,
+            Tag::from_slice(tag),
+        );
+        key.zeroize();
+        match res {
+            Ok(()) => Ok(plaintext),
+            Err(_) => {
+                plaintext.zeroize();
+                Err(Error::InvalidCiphertext)
+            }
+        }
+    }
+}
+
+/// HKDF-SHA256(ikm = shared point, info = DST || g^r || g^x)
+/// split into the AEAD key and nonce
+fn derive_keys<G: GroupEncoding>(
+    shared: &G,
+    ephemeral_key: &G,
+    recipient: &G,
+) -> Result<([u8; KEY_SIZE], [u8; NONCE_SIZE]), Error> {
+    let shared = shared.to_bytes();
+    let ephemeral_key = ephemeral_key.to_bytes();
+    let recipient = recipient.to_bytes();
+    let info = [
+        SHARE_TRANSPORT_DST,
+        ephemeral_key.as_ref(),
+        recipient.as_ref(),
+    ];
+
+    let mut okm = [0u8; KEY_SIZE + NONCE_SIZE];
+    Hkdf::<Sha256>::new(None, shared.as_ref())
+        .expand_multi_info(&info, &mut okm)
+        .map_err(|_| Error::InvalidShare)?;
+    let mut key = [0u8; KEY_SIZE];
+    let mut nonce = [0u8; NONCE_SIZE];
+    key.copy_from_slice(&okm[..KEY_SIZE]);
+    nonce.copy_from_slice(&okm[KEY_SIZE..]);
+    okm.zeroize();
+    Ok((key, nonce))
+}
+
+/// associated data || identifier
+fn aead_associated_data(identifier: u8, associated_data: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(associated_data.len() + 1);
+    aad.extend_from_slice(associated_data);
+    aad.push(identifier);
+    aad
+}
@@ -4,13 +4,22 @@
 */
 
 use super::super::*;
-use crate::{lib::*, util::bytes_to_field};
-use core::marker::PhantomData;
+use crate::Error as SharingError;
+use crate::{
+    codec::{verifier_len, ByteReader, ByteWriter},
+    lib::*,
+    util::{bytes_to_field, sum_of_products},
+};
+use core::{marker::PhantomData, ops::Add};
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
 };
+use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use sha2_10::{Digest, Sha256};
+
+const FELDMAN_VERIFIER_HASH_DST: &[u8] = b"VSSS-RS-V01-FELDMAN-VERIFIER";
 
 /// A Feldman verifier is used to provide integrity checking of shamir shares
 /// `T` commitments are made to be used for verification.
@@ -34,6 +43,43 @@ pub struct FeldmanVerifier<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F
 }
 
 impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> FeldmanVerifier<F, G> {
+    /// Compute the public verification key `g^{f(i)}` of the share
+    /// with `identifier` by evaluating the commitments in the exponent.
+    pub fn public_share(&self, identifier: u8) -> G {
+        let x = F::from(identifier as u64);
+        let mut i = F::one();
+
+        // {1, i, i^2, ..., i^t}
+        let mut powers = Vec::with_capacity(self.commitments.len());
+        for _ in 0..self.commitments.len() {
+            powers.push(i);
+            i *= x;
+        }
+
+        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
+        // the identifier is public so this can be done in variable time
+        sum_of_products(&self.commitments, &powers)
+    }
+
+    /// A SHA-256 digest of the generator and commitments
+    /// that identifies this dealing
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(FELDMAN_VERIFIER_HASH_DST);
+        hasher.update(self.generator.to_bytes());
+        hasher.update((self.commitments.len() as u64).to_be_bytes());
+        for c in &self.commitments {
+            hasher.update(c.to_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    /// Compute the public verification keys `g^{f(i)}` for
+    /// identifiers `1..=limit`.
+    pub fn public_shares(&self, limit: u8) -> Vec<G> {
+        (1..=limit).map(|i| self.public_share(i)).collect()
+    }
+
     /// Check whether the share is valid according this verifier set
     pub fn verify(&self, share: &Share) -> bool {
         let s = bytes_to_field::<F>(share.value());
@@ -42,24 +88,7 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> FeldmanVerifier<F,
         }
 
         let s = s.unwrap();
-        let x = F::from(share.identifier() as u64);
-        let mut i = F::one();
-
-        // FUTURE: execute this sum of products
-        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
-
-        // c_0
-        let mut rhs = self.commitments[0];
-        for v in &self.commitments[1..] {
-            i *= x;
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i;
-        }
+        let rhs = self.public_share(share.identifier());
 
         let lhs: G = -self.generator * s;
 
@@ -67,4 +96,132 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> FeldmanVerifier<F,
 
         res.is_identity().unwrap_u8() == 1
     }
+
+    /// Check whether all the shares are valid according to this verifier set
+    /// at once using a random linear combination of the shares.
+    ///
+    /// This costs a single multi-scalar multiplication over the commitments
+    /// instead of one per share. If the batch fails, each share is checked
+    /// individually and the indices of the invalid shares are returned.
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        &self,
+        shares: &[Share],
+        rng: &mut R,
+    ) -> Result<(), Vec<usize>> {
+        // Σ r_j * i_j^k for each commitment k
+        let mut scalars = Vec::with_capacity(self.commitments.len());
+        scalars.resize(self.commitments.len(), F::zero());
+        // Σ r_j * s_j
+        let mut sum = F::zero();
+
+        for share in shares {
+            let s = match bytes_to_field::<F>(share.value()) {
+                Some(s) => s,
+                None => return Err(self.find_invalid(shares)),
+            };
+            let r = F::random(&mut *rng);
+            let x = F::from(share.identifier() as u64);
+            let mut p = r;
+            for c in scalars.iter_mut() {
+                *c += p;
+                p *= x;
+            }
+            sum += r * s;
+        }
+
+        let rhs: G = sum_of_products(&self.commitments, &scalars);
+        let lhs: G = -self.generator * sum;
+        let res: G = lhs + rhs;
+
+        if res.is_identity().unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(self.find_invalid(shares))
+        }
+    }
+
+    /// The length of [`FeldmanVerifier::to_bytes`]
+    pub fn bytes_len(&self) -> usize {
+        verifier_len::<G>(1, 1, self.commitments.len())
+    }
+
+    /// The canonical encoding, the threshold `t` as one byte followed by the
+    /// generator and the `t` commitments in the canonical encoding of `G`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SharingError> {
+        let mut out = vec![0u8; self.bytes_len()];
+        self.to_bytes_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Write [`FeldmanVerifier::to_bytes`] into `out` returning the number of bytes written
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, SharingError> {
+        let mut writer = ByteWriter::new(out);
+        writer.write_count(self.commitments.len())?;
+        writer.write_group(&self.generator)?;
+        writer.write_groups(&self.commitments)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a verifier written with [`FeldmanVerifier::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SharingError> {
+        let mut reader = ByteReader::new(bytes);
+        let t = reader.read_u8()? as usize;
+        if t < 2 {
+            return Err(SharingError::InvalidEncoding);
+        }
+        let generator = reader.read_group()?;
+        let mut commitments = vec![G::identity(); t];
+        reader.read_groups(&mut commitments)?;
+        reader.finish()?;
+        Ok(Self {
+            generator,
+            commitments,
+            marker: PhantomData,
+        })
+    }
+
+    fn find_invalid(&self, shares: &[Share]) -> Vec<usize> {
+        shares
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !self.verify(s))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> Add for FeldmanVerifier<F, G> {
+    type Output = Result<Self, SharingError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> Add<&FeldmanVerifier<F, G>>
+    for &FeldmanVerifier<F, G>
+{
+    type Output = Result<FeldmanVerifier<F, G>, SharingError>;
+
+    /// Combine the verifiers of several dealers into the verifier
+    /// for the sum of their shares
+    fn add(self, rhs: &FeldmanVerifier<F, G>) -> Self::Output {
+        if self.generator != rhs.generator {
+            return Err(SharingError::InvalidGenerator);
+        }
+        if self.commitments.len() != rhs.commitments.len() {
+            return Err(SharingError::SharingThresholdMismatch);
+        }
+        let commitments = self
+            .commitments
+            .iter()
+            .zip(rhs.commitments.iter())
+            .map(|(a, b)| *a + *b)
+            .collect();
+        Ok(FeldmanVerifier {
+            generator: self.generator,
+            commitments,
+            marker: PhantomData,
+        })
+    }
 }
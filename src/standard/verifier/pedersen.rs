@@ -5,12 +5,22 @@
 
 use super::super::*;
 use super::FeldmanVerifier;
-use crate::{lib::*, util::bytes_to_field};
+use crate::{
+    codec::{verifier_len, ByteReader, ByteWriter},
+    lib::*,
+    util::{bytes_to_field, sum_of_products},
+};
+use crate::{Error as SharingError, HashToGroup};
+use core::{marker::PhantomData, ops::Add};
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
 };
+use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use sha2_10::{Digest, Sha256};
+
+const PEDERSEN_VERIFIER_HASH_DST: &[u8] = b"VSSS-RS-V01-PEDERSEN-VERIFIER";
 
 /// A Pedersen verifier is used to provide integrity checking of shamir shares
 /// `T` commitments are made to be used for verification.
@@ -35,6 +45,20 @@ pub struct PedersenVerifier<F: PrimeField, G: Group + GroupEncoding + ScalarMul<
 }
 
 impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> PedersenVerifier<F, G> {
+    /// A SHA-256 digest of the generators and commitments
+    /// that identifies this dealing
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(PEDERSEN_VERIFIER_HASH_DST);
+        hasher.update(self.generator.to_bytes());
+        hasher.update(self.feldman_verifier.hash());
+        hasher.update((self.commitments.len() as u64).to_be_bytes());
+        for c in &self.commitments {
+            hasher.update(c.to_bytes());
+        }
+        hasher.finalize().into()
+    }
+
     /// Check whether the share is valid according this verifier set
     pub fn verify(&self, share: &Share, blind_share: &Share) -> bool {
         let secret = bytes_to_field::<F>(share.value());
@@ -49,22 +73,17 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> PedersenVerifier<F,
         let x = F::from(share.identifier() as u64);
         let mut i = F::one();
 
-        // FUTURE: execute this sum of products
-        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-        // as a constant time operation using <https://cr.yp.to/papers/pippenger.pdf>
-        // or Guide to Elliptic Curve Cryptography book,
-        // "Algorithm 3.48 Simultaneous multiple point multiplication"
-        // without precomputing the addition but still reduces doublings
-
-        // c_0
-        let mut rhs = self.commitments[0];
-        for v in &self.commitments[1..] {
+        // {1, i, i^2, ..., i^t}
+        let mut powers = Vec::with_capacity(self.commitments.len());
+        for _ in 0..self.commitments.len() {
+            powers.push(i);
             i *= x;
-
-            // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
-            rhs += *v * i;
         }
 
+        // c_0 * c_1^i * c_2^{i^2} ... c_t^{i^t}
+        // the identifier is public so this can be done in variable time
+        let rhs: G = sum_of_products(&self.commitments, &powers);
+
         let g: G = (-self.feldman_verifier.generator) * secret;
         let h: G = (-self.generator) * blinding;
 
@@ -72,4 +91,173 @@ impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> PedersenVerifier<F,
 
         res.is_identity().unwrap_u8() == 1
     }
+
+    /// Check whether all the shares and blind shares are valid according to
+    /// this verifier set at once using a random linear combination of the shares.
+    ///
+    /// This costs a single multi-scalar multiplication over the commitments
+    /// instead of one per share. If the batch fails, each share is checked
+    /// individually and the indices of the invalid shares are returned.
+    /// Shares without a matching blind share are invalid.
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        &self,
+        shares: &[Share],
+        blind_shares: &[Share],
+        rng: &mut R,
+    ) -> Result<(), Vec<usize>> {
+        if shares.len() != blind_shares.len() {
+            return Err(self.find_invalid(shares, blind_shares));
+        }
+
+        // Σ r_j * i_j^k for each commitment k
+        let mut scalars = Vec::with_capacity(self.commitments.len());
+        scalars.resize(self.commitments.len(), F::zero());
+        // Σ r_j * s_j and Σ r_j * b_j
+        let mut secret_sum = F::zero();
+        let mut blinding_sum = F::zero();
+
+        for (share, blind_share) in shares.iter().zip(blind_shares.iter()) {
+            let secret = bytes_to_field::<F>(share.value());
+            let blinding = bytes_to_field::<F>(blind_share.value());
+            if secret.is_none() || blinding.is_none() {
+                return Err(self.find_invalid(shares, blind_shares));
+            }
+            let r = F::random(&mut *rng);
+            let x = F::from(share.identifier() as u64);
+            let mut p = r;
+            for c in scalars.iter_mut() {
+                *c += p;
+                p *= x;
+            }
+            secret_sum += r * secret.unwrap();
+            blinding_sum += r * blinding.unwrap();
+        }
+
+        let rhs: G = sum_of_products(&self.commitments, &scalars);
+        let g: G = (-self.feldman_verifier.generator) * secret_sum;
+        let h: G = (-self.generator) * blinding_sum;
+        let res: G = rhs + g + h;
+
+        if res.is_identity().unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(self.find_invalid(shares, blind_shares))
+        }
+    }
+
+    /// The length of [`PedersenVerifier::to_bytes`]
+    pub fn bytes_len(&self) -> usize {
+        verifier_len::<G>(2, 2, self.commitments.len())
+    }
+
+    /// The canonical encoding, the threshold `t` as one byte followed by the
+    /// blinding generator, the share generator, the `t` Feldman commitments
+    /// and the `t` blinded commitments in the canonical encoding of `G`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SharingError> {
+        let mut out = vec![0u8; self.bytes_len()];
+        self.to_bytes_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Write [`PedersenVerifier::to_bytes`] into `out` returning the number of bytes written
+    pub fn to_bytes_into(&self, out: &mut [u8]) -> Result<usize, SharingError> {
+        if self.feldman_verifier.commitments.len() != self.commitments.len() {
+            return Err(SharingError::SharingThresholdMismatch);
+        }
+        let mut writer = ByteWriter::new(out);
+        writer.write_count(self.commitments.len())?;
+        writer.write_group(&self.generator)?;
+        writer.write_group(&self.feldman_verifier.generator)?;
+        writer.write_groups(&self.feldman_verifier.commitments)?;
+        writer.write_groups(&self.commitments)?;
+        Ok(writer.finish())
+    }
+
+    /// Decode a verifier written with [`PedersenVerifier::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SharingError> {
+        let mut reader = ByteReader::new(bytes);
+        let t = reader.read_u8()? as usize;
+        if t < 2 {
+            return Err(SharingError::InvalidEncoding);
+        }
+        let generator = reader.read_group()?;
+        let feldman_generator = reader.read_group()?;
+        let mut feldman_commitments = vec![G::identity(); t];
+        reader.read_groups(&mut feldman_commitments)?;
+        let mut commitments = vec![G::identity(); t];
+        reader.read_groups(&mut commitments)?;
+        reader.finish()?;
+        Ok(Self {
+            generator,
+            feldman_verifier: FeldmanVerifier {
+                generator: feldman_generator,
+                commitments: feldman_commitments,
+                marker: PhantomData,
+            },
+            commitments,
+        })
+    }
+
+    fn find_invalid(&self, shares: &[Share], blind_shares: &[Share]) -> Vec<usize> {
+        shares
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| {
+                blind_shares
+                    .get(*i)
+                    .map(|b| !self.verify(s, b))
+                    .unwrap_or(true)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F> + HashToGroup> PedersenVerifier<F, G> {
+    /// Check whether the blinding generator is the nothing-up-my-sleeve
+    /// generator from [`HashToGroup::blinding_generator`].
+    ///
+    /// Pedersen commitments are only binding if the dealer doesn't know the
+    /// discrete log of the blinding generator, so recipients should check
+    /// this before trusting a verifier.
+    pub fn verify_blinding_generator(&self) -> bool {
+        self.generator == G::blinding_generator()
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> Add for PedersenVerifier<F, G> {
+    type Output = Result<Self, SharingError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<F: PrimeField, G: Group + GroupEncoding + ScalarMul<F>> Add<&PedersenVerifier<F, G>>
+    for &PedersenVerifier<F, G>
+{
+    type Output = Result<PedersenVerifier<F, G>, SharingError>;
+
+    /// Combine the verifiers of several dealers into the verifier
+    /// for the sum of their secret and blind shares
+    fn add(self, rhs: &PedersenVerifier<F, G>) -> Self::Output {
+        if self.generator != rhs.generator {
+            return Err(SharingError::InvalidGenerator);
+        }
+        if self.commitments.len() != rhs.commitments.len() {
+            return Err(SharingError::SharingThresholdMismatch);
+        }
+        let feldman_verifier = (&self.feldman_verifier + &rhs.feldman_verifier)?;
+        let commitments = self
+            .commitments
+            .iter()
+            .zip(rhs.commitments.iter())
+            .map(|(a, b)| *a + *b)
+            .collect();
+        Ok(PedersenVerifier {
+            generator: self.generator,
+            feldman_verifier,
+            commitments,
+        })
+    }
 }
@@ -2,6 +2,7 @@
     Copyright Michael Lodder. All Rights Reserved.
     SPDX-License-Identifier: Apache-2.0
 */
+mod hash_to_group;
 #[cfg(all(not(feature = "alloc"), not(feature = "std")))]
 mod no_std;
 #[cfg(any(feature = "alloc", feature = "std"))]
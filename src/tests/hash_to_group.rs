@@ -0,0 +1,111 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0
+*/
+use crate::{Error, HashToGroup, PEDERSEN_BLINDING_GENERATOR_DST};
+use elliptic_curve::sec1::ToEncodedPoint;
+
+fn generator_properties<G: HashToGroup>() {
+    let h = G::blinding_generator();
+    assert_eq!(h.is_identity().unwrap_u8(), 0u8);
+    assert_ne!(h, G::generator());
+    assert_eq!(h, G::blinding_generator());
+    assert_eq!(
+        h,
+        G::hash_to_group(&[], PEDERSEN_BLINDING_GENERATOR_DST).unwrap()
+    );
+    assert_ne!(
+        h,
+        G::hash_to_group(b"msg", PEDERSEN_BLINDING_GENERATOR_DST).unwrap()
+    );
+    assert_ne!(h, G::hash_to_group(&[], b"another dst").unwrap());
+    assert_eq!(G::hash_to_group(b"msg", &[]), Err(Error::InvalidGenerator));
+}
+
+#[test]
+fn generators() {
+    generator_properties::<p256::ProjectivePoint>();
+    generator_properties::<k256::ProjectivePoint>();
+    generator_properties::<bls12_381_plus::G1Projective>();
+    generator_properties::<bls12_381_plus::G2Projective>();
+    #[cfg(feature = "secp256k1")]
+    generator_properties::<crate::secp256k1::WrappedProjectivePoint>();
+    #[cfg(feature = "curve25519")]
+    {
+        generator_properties::<crate::curve25519::WrappedRistretto>();
+        generator_properties::<crate::curve25519::WrappedEdwards>();
+    }
+}
+
+// RFC 9380 appendix J test vectors with an empty message
+#[test]
+fn p256_vector() {
+    let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+    let p = p256::ProjectivePoint::hash_to_group(b"", dst).unwrap();
+    let p = p.to_affine().to_encoded_point(false);
+    assert_eq!(
+        hex::encode(p.x().unwrap()),
+        "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4"
+    );
+    assert_eq!(
+        hex::encode(p.y().unwrap()),
+        "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"
+    );
+}
+
+#[test]
+fn k256_vector() {
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    let p = k256::ProjectivePoint::hash_to_group(b"", dst).unwrap();
+    let p = p.to_affine().to_encoded_point(false);
+    assert_eq!(
+        hex::encode(p.x().unwrap()),
+        "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346"
+    );
+    assert_eq!(
+        hex::encode(p.y().unwrap()),
+        "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067"
+    );
+}
+
+#[test]
+fn bls12_381_g1_vector() {
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let p = bls12_381_plus::G1Projective::hash_to_group(b"", dst).unwrap();
+    let x = &bls12_381_plus::G1Affine::from(p).to_uncompressed()[..48];
+    assert_eq!(
+        hex::encode(x),
+        "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1"
+    );
+}
+
+#[test]
+fn bls12_381_g2_vector() {
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let p = bls12_381_plus::G2Projective::hash_to_group(b"", dst).unwrap();
+    let x = &bls12_381_plus::G2Affine::from(p).to_uncompressed()[..96];
+    assert_eq!(
+        hex::encode(x),
+        "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a"
+    );
+}
+
+#[cfg(feature = "curve25519")]
+#[test]
+fn expand_message_xmd_sha512() {
+    use crate::curve25519::expand_message_xmd_sha512;
+    use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
+
+    let long_dst = [7u8; 300];
+    for (msg, dst) in [
+        (&b""[..], &b"QUUX-V01-CS02-with-expander-SHA512-256"[..]),
+        (&b"abc"[..], &b"QUUX-V01-CS02-with-expander-SHA512-256"[..]),
+        (&[0xa5u8; 200][..], &long_dst[..]),
+    ] {
+        let mut expected = [0u8; 64];
+        ExpandMsgXmd::<sha2_10::Sha512>::expand_message(&[msg], dst, 64)
+            .unwrap()
+            .fill_bytes(&mut expected);
+        assert_eq!(expand_message_xmd_sha512(msg, dst).unwrap(), expected);
+    }
+}
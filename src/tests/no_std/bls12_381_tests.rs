@@ -28,7 +28,22 @@ fn valid_tests() {
     combine_single::<Scalar, G1Projective, 33>();
     combine_single::<Scalar, G2Projective, 33>();
     combine_all::<Scalar, G1Projective, 33>();
+    blinding_generator_checks::<Scalar, G1Projective, 33>();
+    aggregate_dealers::<Scalar, G1Projective, 33>();
+    public_shares_match::<Scalar, G1Projective, 33>();
+    verify_batch_culprits::<Scalar, G1Projective, 33>();
+    sum_of_products_matches::<Scalar, G1Projective>();
+    additive_conversion::<Scalar, G1Projective, 33>();
+    ramp_combine::<Scalar, 33>();
+    byte_codec::<Scalar, G1Projective, 33>();
+    byte_codec::<Scalar, G2Projective, 33>();
     combine_all::<Scalar, G2Projective, 33>();
+    blinding_generator_checks::<Scalar, G2Projective, 33>();
+    aggregate_dealers::<Scalar, G2Projective, 33>();
+    public_shares_match::<Scalar, G2Projective, 33>();
+    verify_batch_culprits::<Scalar, G2Projective, 33>();
+    sum_of_products_matches::<Scalar, G2Projective>();
+    additive_conversion::<Scalar, G2Projective, 33>();
 }
 
 #[test]
@@ -101,9 +116,32 @@ fn group_combine() {
     );
 }
 
+#[test]
+fn group_share_serde() {
+    let mut rng = MockRng::default();
+    let sk = Scalar::random(&mut rng);
+    let g1 = Share::<49>::from_group_element(1, <G1Projective as Group>::generator() * sk).unwrap();
+    let g2 = Share::<97>::from_group_element(2, <G2Projective as Group>::generator() * sk).unwrap();
+
+    let json = serde_json::to_string(&g1).unwrap();
+    assert_eq!(json, format!("\"{}\"", hex::encode(g1.0)));
+    assert_eq!(serde_json::from_str::<Share<49>>(&json).unwrap(), g1);
+    let json = serde_json::to_string(&g2).unwrap();
+    assert_eq!(json, format!("\"{}\"", hex::encode(g2.0)));
+    assert_eq!(serde_json::from_str::<Share<97>>(&json).unwrap(), g2);
+    let cbor = serde_cbor::to_vec(&g2).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Share<97>>(&cbor).unwrap(), g2);
+
+    // there is no limit on the size
+    let big = Share([7u8; 300]);
+    let json = serde_json::to_string(&big).unwrap();
+    assert_eq!(json.len(), 602);
+    assert_eq!(serde_json::from_str::<Share<300>>(&json).unwrap(), big);
+}
+
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = Scalar::random(&mut osrng);
     let res =
         Feldman::<2, 3>::split_secret::<Scalar, G1Projective, OsRng, 33>(sk, None, &mut osrng);
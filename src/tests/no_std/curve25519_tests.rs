@@ -26,14 +26,29 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<WrappedScalar, WrappedRistretto, 33>();
     combine_all::<WrappedScalar, WrappedRistretto, 33>();
+    blinding_generator_checks::<WrappedScalar, WrappedRistretto, 33>();
+    aggregate_dealers::<WrappedScalar, WrappedRistretto, 33>();
+    public_shares_match::<WrappedScalar, WrappedRistretto, 33>();
+    verify_batch_culprits::<WrappedScalar, WrappedRistretto, 33>();
+    sum_of_products_matches::<WrappedScalar, WrappedRistretto>();
+    additive_conversion::<WrappedScalar, WrappedRistretto, 33>();
+    ramp_combine::<WrappedScalar, 33>();
+    byte_codec::<WrappedScalar, WrappedRistretto, 33>();
+    byte_codec::<WrappedScalar, WrappedEdwards, 33>();
     combine_single::<WrappedScalar, WrappedEdwards, 33>();
     combine_all::<WrappedScalar, WrappedEdwards, 33>();
+    blinding_generator_checks::<WrappedScalar, WrappedEdwards, 33>();
+    aggregate_dealers::<WrappedScalar, WrappedEdwards, 33>();
+    public_shares_match::<WrappedScalar, WrappedEdwards, 33>();
+    verify_batch_culprits::<WrappedScalar, WrappedEdwards, 33>();
+    sum_of_products_matches::<WrappedScalar, WrappedEdwards>();
+    additive_conversion::<WrappedScalar, WrappedEdwards, 33>();
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng = OsRng::default();
-    let mut osrng7 = rand_7::rngs::OsRng::default();
+    let mut osrng = OsRng;
+    let mut osrng7 = rand_7::rngs::OsRng;
     let sc = Scalar::random(&mut osrng7);
     let sk1 = StaticSecret::from(sc.to_bytes());
     let ske1 = SecretKey::from_bytes(&sc.to_bytes()).unwrap();
@@ -52,8 +67,8 @@ fn key_tests() {
 
 #[test]
 fn feldman_verifier_serde_test() {
-    let mut osrng = OsRng::default();
-    let mut osrng7 = rand_7::rngs::OsRng::default();
+    let mut osrng = OsRng;
+    let mut osrng7 = rand_7::rngs::OsRng;
     let sk = Scalar::random(&mut osrng7);
     let res = Feldman::<2, 3>::split_secret::<WrappedScalar, WrappedRistretto, OsRng, 33>(
         sk.into(),
@@ -94,8 +109,8 @@ fn feldman_verifier_serde_test() {
 
 #[test]
 fn pedersen_verifier_serde_test() {
-    let mut osrng = OsRng::default();
-    let mut osrng7 = rand_7::rngs::OsRng::default();
+    let mut osrng = OsRng;
+    let mut osrng7 = rand_7::rngs::OsRng;
     let sk = Scalar::random(&mut osrng7);
     let res = Pedersen::<2, 3>::split_secret::<WrappedScalar, WrappedEdwards, OsRng, 33>(
         sk.into(),
@@ -3,7 +3,7 @@
     SPDX-License-Identifier: Apache-2.0
 */
 use super::super::utils::MockRng;
-use crate::{Feldman, Pedersen, Shamir, Share};
+use crate::{Feldman, HashToGroup, Pedersen, Shamir, Share};
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
@@ -12,7 +12,7 @@ use zeroize::Zeroize;
 
 pub fn split_invalid_args<
     F: PrimeField + Zeroize,
-    G: Group + GroupEncoding + Default + ScalarMul<F>,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
     const S: usize,
 >() {
     let secret = F::one();
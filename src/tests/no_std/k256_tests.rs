@@ -6,8 +6,9 @@ use super::invalid::*;
 use super::valid::*;
 use crate::{
     secp256k1::{WrappedProjectivePoint, WrappedScalar},
-    Feldman, FeldmanVerifier, Pedersen, PedersenResult, Shamir,
+    Feldman, FeldmanVerifier, Pedersen, PedersenResult, Shamir, Share,
 };
+use core::marker::PhantomData;
 use elliptic_curve::ff::PrimeField;
 use k256::{NonZeroScalar, SecretKey};
 use rand::rngs::OsRng;
@@ -22,11 +23,19 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<WrappedScalar, WrappedProjectivePoint, 33>();
     combine_all::<WrappedScalar, WrappedProjectivePoint, 33>();
+    blinding_generator_checks::<WrappedScalar, WrappedProjectivePoint, 33>();
+    aggregate_dealers::<WrappedScalar, WrappedProjectivePoint, 33>();
+    public_shares_match::<WrappedScalar, WrappedProjectivePoint, 33>();
+    verify_batch_culprits::<WrappedScalar, WrappedProjectivePoint, 33>();
+    sum_of_products_matches::<WrappedScalar, WrappedProjectivePoint>();
+    additive_conversion::<WrappedScalar, WrappedProjectivePoint, 33>();
+    ramp_combine::<WrappedScalar, 33>();
+    byte_codec::<WrappedScalar, WrappedProjectivePoint, 33>();
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let secret = WrappedScalar(*sk.to_nonzero_scalar());
     let res = Shamir::<2, 3>::split_secret::<WrappedScalar, OsRng, 33>(secret, &mut osrng);
@@ -42,7 +51,7 @@ fn key_tests() {
 
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let secret = WrappedScalar(*sk.to_nonzero_scalar());
     let res = Feldman::<2, 3>::split_secret::<WrappedScalar, WrappedProjectivePoint, OsRng, 33>(
@@ -97,3 +106,34 @@ fn verifier_serde_test() {
     let result2 = res.unwrap();
     assert_eq!(result2.blinding, result.blinding);
 }
+
+#[test]
+fn canonical_bytes() {
+    // the same vectors are checked by the std tests
+    let mut out = [0u8; 128];
+    let share = Share::<33>::from_field_element(1, k256::Scalar::from(5u64)).unwrap();
+    let len = share.to_bytes_into(&mut out).unwrap();
+    assert_eq!(
+        hex::encode(&out[..len]),
+        "010000000000000000000000000000000000000000000000000000000000000005"
+    );
+
+    let g = k256::ProjectivePoint::GENERATOR;
+    let verifier = FeldmanVerifier::<k256::Scalar, k256::ProjectivePoint, 2> {
+        generator: g,
+        commitments: [g, g.double()],
+        marker: PhantomData,
+    };
+    let len = verifier.to_bytes_into(&mut out).unwrap();
+    let mut expected = [0u8; 100];
+    hex::decode_to_slice(CANONICAL_FELDMAN_VERIFIER, &mut expected).unwrap();
+    assert_eq!(&out[..len], &expected[..]);
+    assert_eq!(FeldmanVerifier::from_bytes(&out[..len]).unwrap(), verifier);
+}
+
+const CANONICAL_FELDMAN_VERIFIER: &str = concat!(
+    "02",
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+);
@@ -19,11 +19,19 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<Scalar, ProjectivePoint, 33>();
     combine_all::<Scalar, ProjectivePoint, 33>();
+    blinding_generator_checks::<Scalar, ProjectivePoint, 33>();
+    aggregate_dealers::<Scalar, ProjectivePoint, 33>();
+    public_shares_match::<Scalar, ProjectivePoint, 33>();
+    verify_batch_culprits::<Scalar, ProjectivePoint, 33>();
+    sum_of_products_matches::<Scalar, ProjectivePoint>();
+    additive_conversion::<Scalar, ProjectivePoint, 33>();
+    ramp_combine::<Scalar, 33>();
+    byte_codec::<Scalar, ProjectivePoint, 33>();
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let nzs = sk.to_nonzero_scalar();
     let res = Shamir::<2, 3>::split_secret::<Scalar, OsRng, 33>(*nzs.as_ref(), &mut osrng);
@@ -39,7 +47,7 @@ fn key_tests() {
 
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let nzs = sk.to_nonzero_scalar();
     let res = Feldman::<2, 3>::split_secret::<Scalar, ProjectivePoint, OsRng, 33>(
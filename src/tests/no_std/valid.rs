@@ -2,7 +2,13 @@
     Copyright Michael Lodder. All Rights Reserved.
     SPDX-License-Identifier: Apache-2.0
 */
-use crate::{tests::utils::MockRng, util::bytes_to_field, Feldman, Pedersen, Shamir, Share};
+use crate::{
+    sum_of_products,
+    tests::utils::MockRng,
+    util::{bytes_to_field, lagrange},
+    Additive, Error, Feldman, FeldmanVerifier, HashToGroup, Pedersen, PedersenResult,
+    PedersenVerifier, Ramp, Shamir, Share,
+};
 use elliptic_curve::{
     ff::PrimeField,
     group::{Group, GroupEncoding, ScalarMul},
@@ -15,7 +21,7 @@ type Pedersen23 = Pedersen<2, 3>;
 
 pub fn combine_single<
     F: PrimeField + Zeroize,
-    G: Group + GroupEncoding + Default + ScalarMul<F>,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
     const S: usize,
 >() {
     let secret: F = bytes_to_field(b"hello").unwrap();
@@ -95,12 +101,12 @@ pub fn combine_single<
 
 pub fn combine_all<
     F: PrimeField + Zeroize,
-    G: Group + GroupEncoding + Default + ScalarMul<F>,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
     const S: usize,
 >() {
     use rand::rngs::OsRng;
 
-    let mut rng = OsRng::default();
+    let mut rng = OsRng;
     let secret: F = F::random(&mut rng);
 
     let res = Shamir::<3, 5>::split_secret::<F, OsRng, S>(secret, &mut rng);
@@ -166,3 +172,302 @@ pub fn combine_all<
         }
     }
 }
+
+pub fn additive_conversion<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+
+    let res = Additive::<3>::split_secret::<F, MockRng, S>(secret, &mut rng);
+    assert!(res.is_ok());
+    let shares = res.unwrap();
+    let res = Additive::<3>::combine_shares::<F, S>(&shares);
+    assert!(res.is_ok());
+    assert_eq!(secret, res.unwrap());
+    // All shares are required
+    assert!(Additive::<3>::combine_shares::<F, S>(&shares[..2]).is_err());
+
+    // Shamir to additive for the subset {1, 3, 4}
+    let shamir_shares = Shamir::<3, 5>::split_secret::<F, MockRng, S>(secret, &mut rng).unwrap();
+    let participants = [1u8, 3, 4];
+    let mut additive_shares = [Share::<S>::default(); 3];
+    for (a, i) in additive_shares.iter_mut().zip(participants.iter()) {
+        *a = Additive::<3>::from_shamir_share::<F, S>(
+            &shamir_shares[*i as usize - 1],
+            &participants,
+        )
+        .unwrap();
+    }
+    let sum = additive_shares
+        .iter()
+        .fold(F::zero(), |acc, s| acc + s.as_field_element::<F>().unwrap());
+    assert_eq!(secret, sum);
+    assert!(Additive::<3>::from_shamir_share::<F, S>(&shamir_shares[1], &participants).is_err());
+
+    // Additive back to a 2 of 3 Shamir sharing
+    let mut received = [[Share::<S>::default(); 3]; 3];
+    for (j, s) in additive_shares.iter().enumerate() {
+        let res = Additive::<3>::to_shamir::<F, G, MockRng, 2, 3, S>(s, None, &mut rng);
+        assert!(res.is_ok());
+        let (reshares, verifier) = res.unwrap();
+        for (i, r) in reshares.iter().enumerate() {
+            assert!(verifier.verify(r));
+            received[i][j] = *r;
+        }
+    }
+    let mut new_shares = [Share::<S>::default(); 3];
+    for (n, r) in new_shares.iter_mut().zip(received.iter()) {
+        *n = Additive::<3>::combine_reshares::<F, S>(r).unwrap();
+    }
+    let res = Shamir::<2, 3>::combine_shares::<F, S>(&new_shares[1..]);
+    assert!(res.is_ok());
+    assert_eq!(secret, res.unwrap());
+    assert!(Additive::<3>::combine_reshares::<F, S>(&new_shares[..2]).is_err());
+}
+
+pub fn ramp_combine<F: PrimeField + Zeroize, const S: usize>() {
+    let mut rng = MockRng::default();
+    let secret = [
+        F::random(&mut rng),
+        F::random(&mut rng),
+        F::random(&mut rng),
+    ];
+
+    assert!(
+        Ramp::<2, 5, 7>::split_secret::<F, MockRng, S, 2>(&[secret[0], secret[1]], &mut rng)
+            .is_err()
+    );
+    assert!(Ramp::<2, 2, 7>::split_secret::<F, MockRng, S, 0>(&[], &mut rng).is_err());
+    assert!(Ramp::<2, 5, 4>::split_secret::<F, MockRng, S, 3>(&secret, &mut rng).is_err());
+
+    let res = Ramp::<2, 5, 7>::split_secret::<F, MockRng, S, 3>(&secret, &mut rng);
+    assert!(res.is_ok());
+    let shares = res.unwrap();
+
+    let res = Ramp::<2, 5, 7>::combine_shares::<F, S, 3>(&shares);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), secret);
+
+    let subset = [shares[6], shares[1], shares[4], shares[0], shares[3]];
+    let res = Ramp::<2, 5, 7>::combine_shares::<F, S, 3>(&subset);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), secret);
+
+    assert!(Ramp::<2, 5, 7>::combine_shares::<F, S, 3>(&shares[..4]).is_err());
+    let dup = [shares[0], shares[1], shares[2], shares[3], shares[0]];
+    assert!(Ramp::<2, 5, 7>::combine_shares::<F, S, 3>(&dup).is_err());
+}
+
+pub fn sum_of_products_matches<
+    F: PrimeField,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let mut points = [G::identity(); 70];
+    let mut scalars = [F::zero(); 70];
+    for (p, s) in points.iter_mut().zip(scalars.iter_mut()) {
+        *p = G::random(&mut rng);
+        *s = F::random(&mut rng);
+    }
+    scalars[1] = F::zero();
+    scalars[2] = -F::one();
+
+    for n in [0, 1, 3, 4, 15, 16, 64, 70] {
+        let expected = points[..n]
+            .iter()
+            .zip(scalars[..n].iter())
+            .fold(G::identity(), |acc, (p, s)| acc + *p * *s);
+        assert_eq!(sum_of_products(&points[..n], &scalars[..n]), expected);
+    }
+    // Mismatched lengths use the shorter
+    assert_eq!(
+        sum_of_products(&points[..5], &scalars[..4]),
+        sum_of_products(&points[..4], &scalars[..4])
+    );
+}
+
+pub fn verify_batch_culprits<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+
+    let (mut shares, verifier) =
+        Feldman::<3, 6>::split_secret::<F, G, MockRng, S>(secret, None, &mut rng).unwrap();
+    assert!(verifier.verify_batch(&shares, &mut rng).is_ok());
+    shares[1] = Share::from_field_element(2, F::random(&mut rng)).unwrap();
+    shares[4].0[0] = 1;
+    assert_eq!(
+        verifier.verify_batch(&shares, &mut rng),
+        Err([false, true, false, false, true, false])
+    );
+
+    let mut res =
+        Pedersen::<3, 6>::split_secret::<F, G, MockRng, S>(secret, None, None, None, &mut rng)
+            .unwrap();
+    assert!(res
+        .verifier
+        .verify_batch(&res.secret_shares, &res.blind_shares, &mut rng)
+        .is_ok());
+    res.blind_shares[3] = Share::from_field_element(4, F::random(&mut rng)).unwrap();
+    assert_eq!(
+        res.verifier
+            .verify_batch(&res.secret_shares, &res.blind_shares, &mut rng),
+        Err([false, false, false, true, false, false])
+    );
+}
+
+pub fn public_shares_match<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let (shares, verifier) =
+        Feldman::<3, 5>::split_secret::<F, G, MockRng, S>(secret, None, &mut rng).unwrap();
+
+    let public_shares = verifier.public_shares::<5>();
+    for (s, p) in shares.iter().zip(public_shares.iter()) {
+        let value = bytes_to_field::<F>(s.value()).unwrap();
+        assert_eq!(verifier.generator * value, *p);
+        assert_eq!(verifier.public_share(s.identifier()), *p);
+    }
+
+    let participants = [2u8, 3, 4];
+    let mut coefficients = [F::zero(); 3];
+    for (c, i) in coefficients.iter_mut().zip(participants.iter()) {
+        *c = lagrange::<F>(*i, &participants).unwrap();
+    }
+    let public_key = sum_of_products(&public_shares[1..4], &coefficients);
+    assert_eq!(public_key, verifier.generator * secret);
+}
+
+pub fn aggregate_dealers<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+
+    let s1 = F::random(&mut rng);
+    let s2 = F::random(&mut rng);
+    let (shares1, verifier1) =
+        Feldman::<3, 5>::split_secret::<F, G, MockRng, S>(s1, None, &mut rng).unwrap();
+    let (shares2, verifier2) =
+        Feldman::<3, 5>::split_secret::<F, G, MockRng, S>(s2, None, &mut rng).unwrap();
+    let res1 =
+        Pedersen::<3, 5>::split_secret::<F, G, MockRng, S>(s1, None, None, None, &mut rng).unwrap();
+    let res2 =
+        Pedersen::<3, 5>::split_secret::<F, G, MockRng, S>(s2, None, None, None, &mut rng).unwrap();
+
+    let verifier = (verifier1 + verifier2).unwrap();
+    let pedersen_verifier = (res1.verifier + res2.verifier).unwrap();
+
+    let mut shares = [Share::<S>::default(); 5];
+    for (i, share) in shares.iter_mut().enumerate() {
+        *share = Share::sum_field_elements::<F>(&[shares1[i], shares2[i]]).unwrap();
+        assert!(verifier.verify(share));
+
+        let secret_share =
+            Share::<S>::sum_field_elements::<F>(&[res1.secret_shares[i], res2.secret_shares[i]])
+                .unwrap();
+        let blind_share =
+            Share::<S>::sum_field_elements::<F>(&[res1.blind_shares[i], res2.blind_shares[i]])
+                .unwrap();
+        assert!(pedersen_verifier.verify(&secret_share, &blind_share));
+    }
+    let res = Shamir::<3, 5>::combine_shares::<F, S>(&shares[..3]);
+    assert_eq!(res.unwrap(), s1 + s2);
+
+    assert_eq!(
+        Share::<S>::sum_field_elements::<F>(&[shares1[0], shares2[1]]),
+        Err(Error::SharingInvalidIdentifier)
+    );
+
+    let (_, other) =
+        Feldman::<3, 5>::split_secret::<F, G, MockRng, S>(s1, Some(G::random(&mut rng)), &mut rng)
+            .unwrap();
+    assert_eq!((verifier + other), Err(Error::InvalidGenerator));
+}
+
+pub fn blinding_generator_checks<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+
+    let res =
+        Pedersen::<2, 3>::split_secret::<F, G, MockRng, S>(secret, None, None, None, &mut rng)
+            .unwrap();
+    assert_eq!(res.verifier.generator, G::blinding_generator());
+    assert!(res.verifier.verify_blinding_generator());
+
+    let h = G::generator() * F::random(&mut rng);
+    let res =
+        Pedersen::<2, 3>::split_secret::<F, G, MockRng, S>(secret, None, None, Some(h), &mut rng)
+            .unwrap();
+    assert!(!res.verifier.verify_blinding_generator());
+}
+
+pub fn byte_codec<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    const S: usize,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let res =
+        Pedersen::<3, 5>::split_secret::<F, G, MockRng, S>(secret, None, None, None, &mut rng)
+            .unwrap();
+    let mut out = [0u8; 2048];
+
+    let share = &res.secret_shares[0];
+    let len = share.to_bytes_into(&mut out).unwrap();
+    assert_eq!(len, S);
+    assert_eq!(&Share::<S>::from_bytes(&out[..len]).unwrap(), share);
+    assert_eq!(
+        Share::<S>::from_bytes(&out[..len - 1]).unwrap_err(),
+        Error::InvalidEncoding
+    );
+
+    let feldman = &res.verifier.feldman_verifier;
+    let len = feldman.to_bytes_into(&mut out).unwrap();
+    let g_len = G::Repr::default().as_ref().len();
+    assert_eq!(len, 1 + 4 * g_len);
+    assert_eq!(len, feldman.bytes_len());
+    assert_eq!(out[0], 3);
+    assert_eq!(
+        &FeldmanVerifier::<F, G, 3>::from_bytes(&out[..len]).unwrap(),
+        feldman
+    );
+    // the threshold must match
+    assert!(FeldmanVerifier::<F, G, 2>::from_bytes(&out[..len]).is_err());
+    assert_eq!(
+        feldman.to_bytes_into(&mut out[..len - 1]).unwrap_err(),
+        Error::InvalidEncoding
+    );
+
+    let len = res.verifier.to_bytes_into(&mut out).unwrap();
+    assert_eq!(len, 1 + 8 * g_len);
+    let decoded = PedersenVerifier::<F, G, 3>::from_bytes(&out[..len]).unwrap();
+    assert_eq!(decoded.feldman_verifier, res.verifier.feldman_verifier);
+    assert_eq!(decoded.commitments, res.verifier.commitments);
+
+    let len = res.to_bytes_into(&mut out).unwrap();
+    assert_eq!(len, res.bytes_len());
+    let decoded = PedersenResult::<F, G, S, 3, 5>::from_bytes(&out[..len]).unwrap();
+    assert_eq!(decoded.blinding, res.blinding);
+    assert_eq!(decoded.secret_shares, res.secret_shares);
+    assert_eq!(decoded.blind_shares, res.blind_shares);
+    for (s, b) in decoded.secret_shares.iter().zip(&decoded.blind_shares) {
+        assert!(decoded.verifier.verify(s, b));
+    }
+}
@@ -6,14 +6,18 @@ use super::super::utils::MockRng;
 use super::invalid::*;
 use super::valid::*;
 use crate::lib::Vec;
-use crate::{Feldman, FeldmanVerifier, Shamir, Share};
+use crate::{
+    BlsMinPk, BlsMinPkPop, BlsMinSig, BlsMinSigPop, BlsSignatureVariant, Error, Feldman,
+    FeldmanVerifier, HashToGroup, Kzg, KzgProof, KzgSetup, KzgVerifier, Shamir, Share,
+    ThresholdBls, BLS_MIN_PK_DST, BLS_MIN_SIG_DST,
+};
 use bls12_381_plus::{
     multi_miller_loop, ExpandMsgXmd, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective,
     Scalar,
 };
 use elliptic_curve::{
     ff::Field,
-    group::{Curve, Group},
+    group::{Curve, Group, GroupEncoding},
 };
 use rand::rngs::OsRng;
 
@@ -29,7 +33,41 @@ fn valid_tests() {
     combine_single::<Scalar, G1Projective>();
     combine_single::<Scalar, G2Projective>();
     combine_all::<Scalar, G1Projective>();
+    frost_keygen::<Scalar, G1Projective>();
+    gjkr_dkg::<Scalar, G1Projective>();
+    sigma_proofs::<Scalar, G1Projective>();
+    threshold_elgamal::<Scalar, G1Projective>();
+    pvss_deal_and_combine::<Scalar, G1Projective>();
+    share_transport::<Scalar, G1Projective>();
+    blinding_generator_checks::<Scalar, G1Projective>();
+    aggregate_dealers::<Scalar, G1Projective>();
+    public_shares_match::<Scalar, G1Projective>();
+    verify_batch_culprits::<Scalar, G1Projective>();
+    sum_of_products_matches::<Scalar, G1Projective>();
+    additive_conversion::<Scalar, G1Projective>();
+    ramp_combine::<Scalar>();
+    bivariate_sharing::<Scalar>();
+    checked_shamir::<Scalar>();
+    share_envelope::<Scalar>();
+    bech32_encoding::<Scalar, G1Projective>();
+    der_encoding::<Scalar, G1Projective>();
+    byte_codec::<Scalar, G1Projective>();
+    bech32_encoding::<Scalar, G2Projective>();
+    der_encoding::<Scalar, G2Projective>();
+    byte_codec::<Scalar, G2Projective>();
     combine_all::<Scalar, G2Projective>();
+    frost_keygen::<Scalar, G2Projective>();
+    gjkr_dkg::<Scalar, G2Projective>();
+    sigma_proofs::<Scalar, G2Projective>();
+    threshold_elgamal::<Scalar, G2Projective>();
+    pvss_deal_and_combine::<Scalar, G2Projective>();
+    share_transport::<Scalar, G2Projective>();
+    blinding_generator_checks::<Scalar, G2Projective>();
+    aggregate_dealers::<Scalar, G2Projective>();
+    public_shares_match::<Scalar, G2Projective>();
+    verify_batch_culprits::<Scalar, G2Projective>();
+    sum_of_products_matches::<Scalar, G2Projective>();
+    additive_conversion::<Scalar, G2Projective>();
 }
 
 #[test]
@@ -102,9 +140,214 @@ fn group_combine() {
     );
 }
 
+fn threshold_bls<V: BlsSignatureVariant>() {
+    let mut rng = MockRng::default();
+    let secret = Scalar::random(&mut rng);
+    let (shares, verifier) = Feldman { t: 3, n: 5 }
+        .split_secret::<Scalar, V::PublicKey, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    let public_key = verifier.commitments[0];
+
+    let msg = b"threshold bls";
+    let partials: Vec<Share> = shares
+        .iter()
+        .map(|s| ThresholdBls::<V>::partial_sign(s, msg).unwrap())
+        .collect();
+    for p in &partials {
+        assert!(ThresholdBls::<V>::verify_partial(p, &verifier, msg).is_ok());
+        assert_eq!(
+            ThresholdBls::<V>::verify_partial(p, &verifier, b"another message").unwrap_err(),
+            Error::InvalidSignature
+        );
+    }
+
+    let signature = ThresholdBls::<V>::aggregate(&partials[2..], &verifier, msg).unwrap();
+    assert_eq!(
+        signature,
+        V::Signature::hash_to_group(msg, V::DST).unwrap() * secret
+    );
+    assert!(ThresholdBls::<V>::verify(&public_key, msg, &signature).is_ok());
+    assert_eq!(
+        ThresholdBls::<V>::verify(&public_key, b"another message", &signature).unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(
+        ThresholdBls::<V>::verify(&V::PublicKey::identity(), msg, &V::Signature::identity())
+            .unwrap_err(),
+        Error::InvalidSignature
+    );
+
+    assert_eq!(
+        ThresholdBls::<V>::aggregate(&partials[3..], &verifier, msg).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    let mut tampered = partials.clone();
+    tampered[1] = Share::from_group_element(
+        tampered[1].identifier(),
+        V::Signature::hash_to_group(msg, V::DST).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ThresholdBls::<V>::verify_partial(&tampered[1], &verifier, msg).unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(
+        ThresholdBls::<V>::aggregate(&tampered, &verifier, msg).unwrap_err(),
+        Error::InvalidSignature
+    );
+}
+
+#[test]
+fn threshold_bls_tests() {
+    threshold_bls::<BlsMinPk>();
+    threshold_bls::<BlsMinSig>();
+    threshold_bls::<BlsMinPkPop>();
+    threshold_bls::<BlsMinSigPop>();
+
+    // the standard ciphersuites hash with the RFC 9380 suites
+    let msg = b"threshold bls";
+    assert_eq!(
+        G2Projective::hash_to_group(msg, BLS_MIN_PK_DST).unwrap(),
+        G2Projective::hash::<ExpandMsgXmd<sha2::Sha256>>(msg, BLS_MIN_PK_DST)
+    );
+    assert_eq!(
+        G1Projective::hash_to_group(msg, BLS_MIN_SIG_DST).unwrap(),
+        G1Projective::hash::<ExpandMsgXmd<sha2::Sha256>>(msg, BLS_MIN_SIG_DST)
+    );
+}
+
+/// The `sign` vectors from the Ethereum consensus spec BLS tests,
+/// which use the proof of possession min-pk ciphersuite
+#[test]
+fn bls_min_pk_pop_vectors() {
+    let vectors = [
+        (
+            "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+            [0u8; 32],
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55",
+        ),
+        (
+            "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+            [0u8; 32],
+            "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+            "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd100b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+        ),
+        (
+            "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+            [0x56u8; 32],
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+            "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb",
+        ),
+    ];
+    let mut rng = MockRng::default();
+    for (secret, msg, public_key, signature) in &vectors {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(secret).unwrap());
+        bytes.reverse();
+        let secret = Scalar::from_bytes(&bytes).unwrap();
+
+        let res = Feldman { t: 2, n: 3 }
+            .split_secret::<Scalar, G1Projective, MockRng>(secret, None, &mut rng);
+        let (shares, verifier) = res.unwrap();
+        assert_eq!(hex::encode(verifier.commitments[0].to_bytes()), *public_key);
+        let partials: Vec<Share> = shares[1..]
+            .iter()
+            .map(|s| ThresholdBls::<BlsMinPkPop>::partial_sign(s, msg).unwrap())
+            .collect();
+        let aggregated = ThresholdBls::<BlsMinPkPop>::aggregate(&partials, &verifier, msg).unwrap();
+        assert_eq!(hex::encode(aggregated.to_bytes()), *signature);
+        assert!(
+            ThresholdBls::<BlsMinPkPop>::verify(&verifier.commitments[0], msg, &aggregated).is_ok()
+        );
+        // the basic scheme DST gives a different signature
+        assert!(
+            ThresholdBls::<BlsMinPk>::verify(&verifier.commitments[0], msg, &aggregated).is_err()
+        );
+    }
+}
+
+#[test]
+fn kzg_tests() {
+    let mut rng = MockRng::default();
+    let setup = KzgSetup::deterministic(3, b"kzg test").unwrap();
+    assert_eq!(setup, KzgSetup::deterministic(3, b"kzg test").unwrap());
+    let secret = Scalar::random(&mut rng);
+    let (shares, proofs, verifier) = Kzg { t: 3, n: 5 }
+        .split_secret(secret, &setup, &mut rng)
+        .unwrap();
+    for (share, proof) in shares.iter().zip(&proofs) {
+        assert!(verifier.verify(share, proof, &setup));
+    }
+    assert_eq!(
+        Shamir { t: 3, n: 5 }
+            .combine_shares::<Scalar>(&shares[1..4])
+            .unwrap(),
+        secret
+    );
+
+    // the wrong share, proof, commitment or setup is rejected
+    let bad_share = Share::from_field_element(1, Scalar::random(&mut rng)).unwrap();
+    assert!(!verifier.verify(&bad_share, &proofs[0], &setup));
+    assert!(!verifier.verify(&shares[0], &proofs[1], &setup));
+    let mut bad_proof = proofs[0];
+    bad_proof.identifier = 2;
+    assert!(!verifier.verify(&shares[1], &bad_proof, &setup));
+    let other = KzgVerifier {
+        commitment: verifier.commitment + G1Projective::GENERATOR,
+        ..verifier
+    };
+    assert!(!other.verify(&shares[0], &proofs[0], &setup));
+    let other_setup = KzgSetup::new(3, &mut rng).unwrap();
+    assert!(!verifier.verify(&shares[0], &proofs[0], &other_setup));
+
+    // the setup must cover the threshold
+    assert_eq!(
+        Kzg { t: 4, n: 5 }
+            .split_secret(secret, &setup, &mut rng)
+            .unwrap_err(),
+        Error::SharingLimitLessThanThreshold
+    );
+    let small_setup = KzgSetup::deterministic(2, b"kzg test").unwrap();
+    assert!(!verifier.verify_degree(&small_setup));
+    assert!(!verifier.verify(&shares[0], &proofs[0], &small_setup));
+
+    // a dealer can't commit to a polynomial with degree at or above the threshold
+    let large_setup = KzgSetup::deterministic(5, b"kzg test").unwrap();
+    let (shares, proofs, verifier) = Kzg { t: 3, n: 5 }
+        .split_secret(secret, &large_setup, &mut rng)
+        .unwrap();
+    assert!(verifier.verify_degree(&large_setup));
+    for (share, proof) in shares.iter().zip(&proofs) {
+        assert!(verifier.verify(share, proof, &large_setup));
+    }
+    let (shares, proofs, mut cheat) = Kzg { t: 5, n: 5 }
+        .split_secret(secret, &large_setup, &mut rng)
+        .unwrap();
+    assert!(cheat.verify_degree(&large_setup));
+    cheat.t = 3;
+    assert!(!cheat.verify_degree(&large_setup));
+    for (share, proof) in shares.iter().zip(&proofs) {
+        assert!(!cheat.verify(share, proof, &large_setup));
+    }
+    // the degree 4 commitment shifted by D - t = 2 would need g1^{τ^6}
+    cheat.degree_proof = cheat.commitment;
+    assert!(!cheat.verify_degree(&large_setup));
+    let mut bad_setup = large_setup.clone();
+    bad_setup.g2_powers.pop();
+    assert!(!verifier.verify_degree(&bad_setup));
+
+    let res = serde_json::to_string(&verifier).unwrap();
+    assert_eq!(serde_json::from_str::<KzgVerifier>(&res).unwrap(), verifier);
+    let res = serde_bare::to_vec(&proofs[0]).unwrap();
+    assert_eq!(serde_bare::from_slice::<KzgProof>(&res).unwrap(), proofs[0]);
+    let res = serde_bare::to_vec(&setup).unwrap();
+    assert_eq!(serde_bare::from_slice::<KzgSetup>(&res).unwrap(), setup);
+}
+
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = Scalar::random(&mut osrng);
     let res =
         Feldman { t: 2, n: 3 }.split_secret::<Scalar, G1Projective, OsRng>(sk, None, &mut osrng);
@@ -5,12 +5,16 @@
 
 use super::invalid::*;
 use super::valid::*;
+use crate::lib::Vec;
 use crate::{
     curve25519::{WrappedEdwards, WrappedRistretto, WrappedScalar},
-    Feldman, FeldmanVerifier, Pedersen, PedersenResult, PedersenVerifier, Shamir,
+    tests::utils::MockRng,
+    Feldman, FeldmanVerifier, Frost, FrostEd25519Sha512, FrostRistretto255Sha512, Pedersen,
+    PedersenResult, PedersenVerifier, Shamir,
 };
 use curve25519_dalek::scalar::Scalar;
-use ed25519_dalek::SecretKey;
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature};
+use elliptic_curve::group::GroupEncoding;
 use x25519_dalek::StaticSecret;
 
 #[test]
@@ -25,14 +29,163 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<WrappedScalar, WrappedRistretto>();
     combine_all::<WrappedScalar, WrappedRistretto>();
+    frost_keygen::<WrappedScalar, WrappedRistretto>();
+    frost_sign::<FrostRistretto255Sha512>();
+    gjkr_dkg::<WrappedScalar, WrappedRistretto>();
+    sigma_proofs::<WrappedScalar, WrappedRistretto>();
+    threshold_elgamal::<WrappedScalar, WrappedRistretto>();
+    pvss_deal_and_combine::<WrappedScalar, WrappedRistretto>();
+    share_transport::<WrappedScalar, WrappedRistretto>();
+    blinding_generator_checks::<WrappedScalar, WrappedRistretto>();
+    aggregate_dealers::<WrappedScalar, WrappedRistretto>();
+    public_shares_match::<WrappedScalar, WrappedRistretto>();
+    verify_batch_culprits::<WrappedScalar, WrappedRistretto>();
+    sum_of_products_matches::<WrappedScalar, WrappedRistretto>();
+    additive_conversion::<WrappedScalar, WrappedRistretto>();
+    ramp_combine::<WrappedScalar>();
+    bivariate_sharing::<WrappedScalar>();
+    checked_shamir::<WrappedScalar>();
+    share_envelope::<WrappedScalar>();
+    bech32_encoding::<WrappedScalar, WrappedRistretto>();
+    der_encoding::<WrappedScalar, WrappedRistretto>();
+    byte_codec::<WrappedScalar, WrappedRistretto>();
+    bech32_encoding::<WrappedScalar, WrappedEdwards>();
+    der_encoding::<WrappedScalar, WrappedEdwards>();
+    byte_codec::<WrappedScalar, WrappedEdwards>();
     combine_single::<WrappedScalar, WrappedEdwards>();
     combine_all::<WrappedScalar, WrappedEdwards>();
+    frost_keygen::<WrappedScalar, WrappedEdwards>();
+    frost_sign::<FrostEd25519Sha512>();
+    gjkr_dkg::<WrappedScalar, WrappedEdwards>();
+    sigma_proofs::<WrappedScalar, WrappedEdwards>();
+    threshold_elgamal::<WrappedScalar, WrappedEdwards>();
+    pvss_deal_and_combine::<WrappedScalar, WrappedEdwards>();
+    share_transport::<WrappedScalar, WrappedEdwards>();
+    let secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let keypair = Keypair {
+        public: PublicKey::from(&secret),
+        secret,
+    };
+    dealer_signatures::<WrappedScalar, WrappedEdwards, _, _>(&keypair, &keypair.public);
+    share_complaints::<WrappedScalar, WrappedEdwards, _, _>(&keypair, &keypair.public);
+    blinding_generator_checks::<WrappedScalar, WrappedEdwards>();
+    aggregate_dealers::<WrappedScalar, WrappedEdwards>();
+    public_shares_match::<WrappedScalar, WrappedEdwards>();
+    verify_batch_culprits::<WrappedScalar, WrappedEdwards>();
+    sum_of_products_matches::<WrappedScalar, WrappedEdwards>();
+    additive_conversion::<WrappedScalar, WrappedEdwards>();
+}
+
+#[test]
+fn frost_rfc9591_tests() {
+    frost_rfc9591::<FrostEd25519Sha512>(&FrostVector {
+        group_secret_key: "7b1c33d3f5291d85de664833beb1ad469f7fb6025a0ec78b3a790c6e13a98304",
+        group_public_key: "15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673",
+        share_polynomial_coefficient: "178199860edd8c62f5212ee91eff1295d0d670ab4ed4506866bae57e7030b204",
+        participant_shares: [
+            "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509",
+            "a91e66e012e4364ac9aaa405fcafd370402d9859f7b6685c07eed76bf409e80d",
+            "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02",
+        ],
+        signers: [
+            FrostSignerVector {
+                identifier: 1,
+                hiding_nonce_randomness: "0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec",
+                binding_nonce_randomness: "69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501",
+                hiding_nonce: "812d6104142944d5a55924de6d49940956206909f2acaeedecda2b726e630407",
+                binding_nonce: "b1110165fc2334149750b28dd813a39244f315cff14d4e89e6142f262ed83301",
+                hiding_nonce_commitment: "b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de3",
+                binding_nonce_commitment: "67e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932",
+                binding_factor: "f2cb9d7dd9beff688da6fcc83fa89046b3479417f47f55600b106760eb3b5603",
+                sig_share: "001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603",
+            },
+            FrostSignerVector {
+                identifier: 3,
+                hiding_nonce_randomness: "86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f",
+                binding_nonce_randomness: "13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775",
+                hiding_nonce: "c256de65476204095ebdc01bd11dc10e57b36bc96284595b8215222374f99c0e",
+                binding_nonce: "243d71944d929063bc51205714ae3c2218bd3451d0214dfb5aeec2a90c35180d",
+                hiding_nonce_commitment: "cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec91",
+                binding_nonce_commitment: "7487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552",
+                binding_factor: "b087686bf35a13f3dc78e780a34b0fe8a77fef1b9938c563f5573d71d8d7890f",
+                sig_share: "bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007",
+            },
+        ],
+        sig: "36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbebd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b",
+    });
+    frost_rfc9591::<FrostRistretto255Sha512>(&FrostVector {
+        group_secret_key: "1b25a55e463cfd15cf14a5d3acc3d15053f08da49c8afcf3ab265f2ebc4f970b",
+        group_public_key: "e2a62f39eede11269e3bd5a7d97554f5ca384f9f6d3dd9c3c0d05083c7254f57",
+        share_polynomial_coefficient: "410f8b744b19325891d73736923525a4f596c805d060dfb9c98009d34e3fec02",
+        participant_shares: [
+            "5c3430d391552f6e60ecdc093ff9f6f4488756aa6cebdbad75a768010b8f830e",
+            "b06fc5eac20b4f6e1b271d9df2343d843e1e1fb03c4cbb673f2872d459ce6f01",
+            "f17e505f0e2581c6acfe54d3846a622834b5e7b50cad9a2109a97ba7a80d5c04",
+        ],
+        signers: [
+            FrostSignerVector {
+                identifier: 1,
+                hiding_nonce_randomness: "f595a133b4d95c6e1f79887220c8b275ce6277e7f68a6640e1e7140f9be2fb5c",
+                binding_nonce_randomness: "34dd1001360e3513cb37bebfabe7be4a32c5bb91ba19fbd4360d039111f0fbdc",
+                hiding_nonce: "214f2cabb86ed71427ea7ad4283b0fae26b6746c801ce824b83ceb2b99278c03",
+                binding_nonce: "c9b8f5e16770d15603f744f8694c44e335e8faef00dad182b8d7a34a62552f0c",
+                hiding_nonce_commitment: "965def4d0958398391fc06d8c2d72932608b1e6255226de4fb8d972dac15fd57",
+                binding_nonce_commitment: "ec5170920660820007ae9e1d363936659ef622f99879898db86e5bf1d5bf2a14",
+                binding_factor: "8967fd70fa06a58e5912603317fa94c77626395a695a0e4e4efc4476662eba0c",
+                sig_share: "9285f875923ce7e0c491a592e9ea1865ec1b823ead4854b48c8a46287749ee09",
+            },
+            FrostSignerVector {
+                identifier: 3,
+                hiding_nonce_randomness: "daa0cf42a32617786d390e0c7edfbf2efbd428037069357b5173ae61d6dd5d5e",
+                binding_nonce_randomness: "b4387e72b2e4108ce4168931cc2c7fcce5f345a5297368952c18b5fc8473f050",
+                hiding_nonce: "3f7927872b0f9051dd98dd73eb2b91494173bbe0feb65a3e7e58d3e2318fa40f",
+                binding_nonce: "ffd79445fb8030f0a3ddd3861aa4b42b618759282bfe24f1f9304c7009728305",
+                hiding_nonce_commitment: "480e06e3de182bf83489c45d7441879932fd7b434a26af41455756264fbd5d6e",
+                binding_nonce_commitment: "3064746dfd3c1862ef58fc68c706da287dd925066865ceacc816b3a28c7b363b",
+                binding_factor: "f2c1bb7c33a10511158c2f1766a4a5fadf9f86f2a92692ed333128277cc31006",
+                sig_share: "7cb211fe0e3d59d25db6e36b3fb32344794139602a7b24f1ae0dc4e26ad7b908",
+            },
+        ],
+        sig: "fc45655fbc66bbffad654ea4ce5fdae253a49a64ace25d9adb62010dd9fb25552164141787162e5b4cab915b4aa45d94655dbb9ed7c378a53b980a0be220a802",
+    });
+}
+
+#[test]
+fn frost_ed25519_compatible() {
+    let mut rng = MockRng::default();
+    let packages = frost_key_packages::<FrostEd25519Sha512>(2, 3);
+    let message = b"frost ed25519 test message";
+    let signers = [&packages[0], &packages[2]];
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+        .iter()
+        .map(|k| Frost::<FrostEd25519Sha512>::commit(k, &mut rng))
+        .unzip();
+    let shares: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(k, n)| Frost::<FrostEd25519Sha512>::sign(k, n, &commitments, message).unwrap())
+        .collect();
+    let signature = Frost::<FrostEd25519Sha512>::aggregate(
+        &commitments,
+        message,
+        &shares,
+        &packages[0].verifying_key,
+        &packages[0].verifying_shares,
+    )
+    .unwrap();
+
+    let public_key = PublicKey::from_bytes(&packages[0].verifying_key.to_bytes()).unwrap();
+    let signature = Signature::from_bytes(&signature.to_bytes()).unwrap();
+    assert!(public_key.verify_strict(message, &signature).is_ok());
+    assert!(public_key
+        .verify_strict(b"another message", &signature)
+        .is_err());
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng_7 = rand_7::rngs::OsRng::default();
-    let mut osrng_8 = rand::rngs::OsRng::default();
+    let mut osrng_7 = rand_7::rngs::OsRng;
+    let mut osrng_8 = rand::rngs::OsRng;
     let sc = Scalar::random(&mut osrng_7);
     let sk1 = StaticSecret::from(sc.to_bytes());
     let ske1 = SecretKey::from_bytes(&sc.to_bytes()).unwrap();
@@ -52,8 +205,8 @@ fn key_tests() {
 
 #[test]
 fn feldman_verifier_serde_test() {
-    let mut osrng_7 = rand_7::rngs::OsRng::default();
-    let mut osrng_8 = rand::rngs::OsRng::default();
+    let mut osrng_7 = rand_7::rngs::OsRng;
+    let mut osrng_8 = rand::rngs::OsRng;
     let sk = Scalar::random(&mut osrng_7);
     let res = Feldman { t: 2, n: 3 }
         .split_secret::<WrappedScalar, WrappedRistretto, rand::rngs::OsRng>(
@@ -96,8 +249,8 @@ fn feldman_verifier_serde_test() {
 
 #[test]
 fn pedersen_verifier_serde_test() {
-    let mut osrng_7 = rand_7::rngs::OsRng::default();
-    let mut osrng_8 = rand::rngs::OsRng::default();
+    let mut osrng_7 = rand_7::rngs::OsRng;
+    let mut osrng_8 = rand::rngs::OsRng;
     let sk = Scalar::random(&mut osrng_7);
     let res = Pedersen { t: 2, n: 3 }
         .split_secret::<WrappedScalar, WrappedEdwards, rand::rngs::OsRng>(
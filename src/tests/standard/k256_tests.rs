@@ -6,11 +6,15 @@ use super::invalid::*;
 use super::valid::*;
 use crate::{
     secp256k1::{WrappedProjectivePoint, WrappedScalar},
-    Feldman, FeldmanVerifier, Shamir,
+    tests::utils::MockRng,
+    Error, Feldman, FeldmanVerifier, FrostSecp256k1Sha256, Shamir, Share, ShareEnvelope,
+    ShareScheme,
 };
-use elliptic_curve::ff::PrimeField;
+use core::marker::PhantomData;
+use elliptic_curve::ff::{Field, PrimeField};
 use k256::{NonZeroScalar, SecretKey};
 use rand::rngs::OsRng;
+use std::vec::Vec;
 
 #[test]
 fn invalid_tests() {
@@ -22,11 +26,79 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<WrappedScalar, WrappedProjectivePoint>();
     combine_all::<WrappedScalar, WrappedProjectivePoint>();
+    frost_keygen::<WrappedScalar, WrappedProjectivePoint>();
+    frost_sign::<FrostSecp256k1Sha256>();
+    gjkr_dkg::<WrappedScalar, WrappedProjectivePoint>();
+    sigma_proofs::<WrappedScalar, WrappedProjectivePoint>();
+    threshold_elgamal::<WrappedScalar, WrappedProjectivePoint>();
+    pvss_deal_and_combine::<WrappedScalar, WrappedProjectivePoint>();
+    share_transport::<WrappedScalar, WrappedProjectivePoint>();
+    let signing_key = k256::ecdsa::SigningKey::random(&mut MockRng::default());
+    dealer_signatures::<WrappedScalar, WrappedProjectivePoint, _, _>(
+        &signing_key,
+        signing_key.verifying_key(),
+    );
+    share_complaints::<WrappedScalar, WrappedProjectivePoint, _, _>(
+        &signing_key,
+        signing_key.verifying_key(),
+    );
+    blinding_generator_checks::<WrappedScalar, WrappedProjectivePoint>();
+    aggregate_dealers::<WrappedScalar, WrappedProjectivePoint>();
+    public_shares_match::<WrappedScalar, WrappedProjectivePoint>();
+    verify_batch_culprits::<WrappedScalar, WrappedProjectivePoint>();
+    sum_of_products_matches::<WrappedScalar, WrappedProjectivePoint>();
+    additive_conversion::<WrappedScalar, WrappedProjectivePoint>();
+    ramp_combine::<WrappedScalar>();
+    bivariate_sharing::<WrappedScalar>();
+    checked_shamir::<WrappedScalar>();
+    share_envelope::<WrappedScalar>();
+    bech32_encoding::<WrappedScalar, WrappedProjectivePoint>();
+    der_encoding::<WrappedScalar, WrappedProjectivePoint>();
+    byte_codec::<WrappedScalar, WrappedProjectivePoint>();
+}
+
+#[test]
+fn frost_rfc9591_tests() {
+    frost_rfc9591::<FrostSecp256k1Sha256>(&FrostVector {
+        group_secret_key: "0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114",
+        group_public_key: "02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f",
+        share_polynomial_coefficient: "fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579",
+        participant_shares: [
+            "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+            "04f0feac2edcedc6ce1253b7fab8c86b856a797f44d83d82a385554e6e401984",
+            "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+        ],
+        signers: [
+            FrostSignerVector {
+                identifier: 1,
+                hiding_nonce_randomness: "7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2",
+                binding_nonce_randomness: "47acab018f116020c10cb9b9abdc7ac10aae1b48ca6e36dc15acb6ec9be5cdc5",
+                hiding_nonce: "841d3a6450d7580b4da83c8e618414d0f024391f2aeb511d7579224420aa81f0",
+                binding_nonce: "8d2624f532af631377f33cf44b5ac5f849067cae2eacb88680a31e77c79b5a80",
+                hiding_nonce_commitment: "03c699af97d26bb4d3f05232ec5e1938c12f1e6ae97643c8f8f11c9820303f1904",
+                binding_nonce_commitment: "02fa2aaccd51b948c9dc1a325d77226e98a5a3fe65fe9ba213761a60123040a45e",
+                binding_factor: "3e08fe561e075c653cbfd46908a10e7637c70c74f0a77d5fd45d1a750c739ec6",
+                sig_share: "c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197",
+            },
+            FrostSignerVector {
+                identifier: 3,
+                hiding_nonce_randomness: "e6cc56ccbd0502b3f6f831d91e2ebd01c4de0479e0191b66895a4ffd9b68d544",
+                binding_nonce_randomness: "7203d55eb82a5ca0d7d83674541ab55f6e76f1b85391d2c13706a89a064fd5b9",
+                hiding_nonce: "2b19b13f193f4ce83a399362a90cdc1e0ddcd83e57089a7af0bdca71d47869b2",
+                binding_nonce: "7a443bde83dc63ef52dda354005225ba0e553243402a4705ce28ffaafe0f5b98",
+                hiding_nonce_commitment: "03077507ba327fc074d2793955ef3410ee3f03b82b4cdc2370f71d865beb926ef6",
+                binding_nonce_commitment: "02ad53031ddfbbacfc5fbda3d3b0c2445c8e3e99cbc4ca2db2aa283fa68525b135",
+                binding_factor: "93f79041bb3fd266105be251adaeb5fd7f8b104fb554a4ba9a0becea48ddbfd7",
+                sig_share: "0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d",
+            },
+        ],
+        sig: "0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324",
+    });
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let secret = WrappedScalar(*sk.to_nonzero_scalar());
     let res = Shamir { t: 2, n: 3 }.split_secret::<WrappedScalar, OsRng>(secret, &mut osrng);
@@ -42,7 +114,7 @@ fn key_tests() {
 
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let secret = WrappedScalar(*sk.to_nonzero_scalar());
     let res = Feldman { t: 2, n: 3 }
@@ -79,3 +151,57 @@ fn verifier_serde_test() {
     let verifier2 = res.unwrap();
     assert_eq!(verifier.generator, verifier2.generator);
 }
+
+#[test]
+fn envelope_curve_mismatch() {
+    let mut rng = MockRng::default();
+    let shares = Shamir { t: 2, n: 3 }
+        .split_secret::<WrappedScalar, MockRng>(WrappedScalar::random(&mut rng), &mut rng)
+        .unwrap();
+    let envelopes = ShareEnvelope::wrap_all::<WrappedScalar, MockRng>(
+        ShareScheme::Shamir,
+        2,
+        &shares,
+        &mut rng,
+    )
+    .unwrap();
+    // the raw shares combine to garbage as p256 scalars but the envelopes refuse
+    assert!(Shamir { t: 2, n: 3 }
+        .combine_shares::<p256::Scalar>(&shares)
+        .is_ok());
+    assert_eq!(
+        ShareEnvelope::combine::<p256::Scalar>(&envelopes).unwrap_err(),
+        Error::InvalidEnvelope
+    );
+    assert_eq!(
+        envelopes[0].open::<bls12_381_plus::Scalar>().unwrap_err(),
+        Error::InvalidEnvelope
+    );
+}
+
+#[test]
+fn canonical_bytes() {
+    // the same vectors are checked by the no_std tests
+    let share = Share::from_field_element(1, k256::Scalar::from(5u64)).unwrap();
+    assert_eq!(
+        hex::encode(share.to_bytes()),
+        "010000000000000000000000000000000000000000000000000000000000000005"
+    );
+
+    let g = k256::ProjectivePoint::GENERATOR;
+    let verifier = FeldmanVerifier::<k256::Scalar, k256::ProjectivePoint> {
+        generator: g,
+        commitments: Vec::from([g, g.double()]),
+        marker: PhantomData,
+    };
+    let bytes = verifier.to_bytes().unwrap();
+    assert_eq!(hex::encode(&bytes), CANONICAL_FELDMAN_VERIFIER);
+    assert_eq!(FeldmanVerifier::from_bytes(&bytes).unwrap(), verifier);
+}
+
+const CANONICAL_FELDMAN_VERIFIER: &str = concat!(
+    "02",
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+);
@@ -4,7 +4,7 @@
 */
 use super::invalid::*;
 use super::valid::*;
-use crate::{Feldman, FeldmanVerifier, Shamir};
+use crate::{tests::utils::MockRng, Feldman, FeldmanVerifier, Shamir};
 use elliptic_curve::ff::PrimeField;
 use p256::{NonZeroScalar, ProjectivePoint, Scalar, SecretKey};
 use rand::rngs::OsRng;
@@ -19,11 +19,33 @@ fn invalid_tests() {
 fn valid_tests() {
     combine_single::<Scalar, ProjectivePoint>();
     combine_all::<Scalar, ProjectivePoint>();
+    frost_keygen::<Scalar, ProjectivePoint>();
+    gjkr_dkg::<Scalar, ProjectivePoint>();
+    sigma_proofs::<Scalar, ProjectivePoint>();
+    threshold_elgamal::<Scalar, ProjectivePoint>();
+    pvss_deal_and_combine::<Scalar, ProjectivePoint>();
+    share_transport::<Scalar, ProjectivePoint>();
+    let signing_key = p256::ecdsa::SigningKey::random(&mut MockRng::default());
+    dealer_signatures::<Scalar, ProjectivePoint, _, _>(&signing_key, &signing_key.verifying_key());
+    share_complaints::<Scalar, ProjectivePoint, _, _>(&signing_key, &signing_key.verifying_key());
+    blinding_generator_checks::<Scalar, ProjectivePoint>();
+    aggregate_dealers::<Scalar, ProjectivePoint>();
+    public_shares_match::<Scalar, ProjectivePoint>();
+    verify_batch_culprits::<Scalar, ProjectivePoint>();
+    sum_of_products_matches::<Scalar, ProjectivePoint>();
+    additive_conversion::<Scalar, ProjectivePoint>();
+    ramp_combine::<Scalar>();
+    bivariate_sharing::<Scalar>();
+    checked_shamir::<Scalar>();
+    share_envelope::<Scalar>();
+    bech32_encoding::<Scalar, ProjectivePoint>();
+    der_encoding::<Scalar, ProjectivePoint>();
+    byte_codec::<Scalar, ProjectivePoint>();
 }
 
 #[test]
 fn key_tests() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let nzs = sk.to_nonzero_scalar();
     let res = Shamir { t: 2, n: 3 }.split_secret::<Scalar, OsRng>(*nzs.as_ref(), &mut osrng);
@@ -39,7 +61,7 @@ fn key_tests() {
 
 #[test]
 fn verifier_serde_test() {
-    let mut osrng = OsRng::default();
+    let mut osrng = OsRng;
     let sk = SecretKey::random(&mut osrng);
     let nzs = sk.to_nonzero_scalar();
     let res = Feldman { t: 2, n: 3 }.split_secret::<Scalar, ProjectivePoint, OsRng>(
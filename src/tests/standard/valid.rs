@@ -2,16 +2,28 @@
     Copyright Michael Lodder. All Rights Reserved.
     SPDX-License-Identifier: Apache-2.0
 */
-use crate::{tests::utils::MockRng, util::bytes_to_field, Feldman, Pedersen, Shamir, Share};
+use crate::lib::{String, Vec};
+use crate::standard::bech32;
+use crate::{
+    nonce_generate, sum_of_products, tests::utils::MockRng, util::bytes_to_field, Additive,
+    Bivariate, BivariatePoint, BivariateShare, CheckedReveal, CheckedShamir, CheckedShare,
+    ComplaintVerdict, CurveIdentifier, DealerSignature, DealerSigningKey, DealerVerifyingKey,
+    DleqProof, ElGamalCiphertext, EncryptedShare, Error, Feldman, FeldmanVerifier, Frost,
+    FrostCiphersuite, FrostKeyPackage, FrostKeygenParticipant, FrostSigningCommitments,
+    FrostSigningNonces, GjkrParticipant, HashToGroup, OkamotoProof, Pedersen, PedersenResult,
+    PedersenVerifier, Pvss, Ramp, SchnorrProof, Shamir, Share, ShareComplaint, ShareEnvelope,
+    ShareScheme, SignedShare, SigningSession, PVSS_COMMITMENT_GENERATOR_DST,
+};
 use elliptic_curve::{
-    ff::PrimeField,
+    ff::{Field, PrimeField},
     group::{Group, GroupEncoding, ScalarMul},
 };
+use std::string::ToString;
 use zeroize::Zeroize;
 
 pub fn combine_single<
     F: PrimeField + Zeroize,
-    G: Group + GroupEncoding + Default + ScalarMul<F>,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
 >() {
     let shamir = Shamir { t: 2, n: 3 };
     let secret: F = bytes_to_field(b"hello").unwrap();
@@ -91,11 +103,14 @@ pub fn combine_single<
     assert_eq!(secret, secret_1);
 }
 
-pub fn combine_all<F: PrimeField + Zeroize, G: Group + GroupEncoding + Default + ScalarMul<F>>() {
+pub fn combine_all<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
     use rand::rngs::OsRng;
 
     let shamir = Shamir { t: 3, n: 5 };
-    let mut rng = OsRng::default();
+    let mut rng = OsRng;
     let secret: F = F::random(&mut rng);
 
     let res = shamir.split_secret::<F, OsRng>(secret, &mut rng);
@@ -166,3 +181,1730 @@ pub fn combine_all<F: PrimeField + Zeroize, G: Group + GroupEncoding + Default +
         }
     }
 }
+
+pub fn additive_conversion<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+
+    let additive = Additive { n: 3 };
+    let res = additive.split_secret::<F, MockRng>(secret, &mut rng);
+    assert!(res.is_ok());
+    let shares = res.unwrap();
+    let res = additive.combine_shares::<F>(&shares);
+    assert!(res.is_ok());
+    assert_eq!(secret, res.unwrap());
+    // All shares are required
+    assert!(additive.combine_shares::<F>(&shares[..2]).is_err());
+
+    // Shamir to additive for the subset {1, 3, 4}
+    let shamir = Shamir { t: 3, n: 5 };
+    let shamir_shares = shamir.split_secret::<F, MockRng>(secret, &mut rng).unwrap();
+    let participants = [1u8, 3, 4];
+    let additive_shares: Vec<Share> = participants
+        .iter()
+        .map(|i| {
+            Additive::from_shamir_share::<F>(&shamir_shares[*i as usize - 1], &participants)
+                .unwrap()
+        })
+        .collect();
+    let sum = additive_shares
+        .iter()
+        .fold(F::zero(), |acc, s| acc + s.as_field_element::<F>().unwrap());
+    assert_eq!(secret, sum);
+    assert!(Additive::from_shamir_share::<F>(&shamir_shares[1], &participants).is_err());
+
+    // Additive back to a 2 of 3 Shamir sharing
+    let mut received = vec![Vec::new(); 3];
+    for s in &additive_shares {
+        let res = Additive::to_shamir::<F, G, MockRng>(s, 2, 3, None, &mut rng);
+        assert!(res.is_ok());
+        let (reshares, verifier) = res.unwrap();
+        for (i, r) in reshares.into_iter().enumerate() {
+            assert!(verifier.verify(&r));
+            received[i].push(r);
+        }
+    }
+    let new_shares: Vec<Share> = received
+        .iter()
+        .map(|r| Additive::combine_reshares::<F>(r).unwrap())
+        .collect();
+    let res = Shamir { t: 2, n: 3 }.combine_shares::<F>(&new_shares[1..]);
+    assert!(res.is_ok());
+    assert_eq!(secret, res.unwrap());
+    assert!(
+        Additive::combine_reshares::<F>(&[new_shares[0].clone(), new_shares[1].clone()]).is_err()
+    );
+}
+
+pub fn bivariate_sharing<F: PrimeField + Zeroize>() {
+    let mut rng = MockRng::default();
+    let bivariate = Bivariate { t: 3, n: 5 };
+    let secret = F::random(&mut rng);
+    let shares = bivariate
+        .split_secret::<F, MockRng>(secret, &mut rng)
+        .unwrap();
+    assert_eq!(shares.len(), 5);
+
+    // every pair of participants agrees
+    for a in &shares {
+        assert!(bivariate.verify_share(a));
+        for b in &shares {
+            assert!(b.verify_point(&a.point(b.identifier).unwrap()));
+            assert!(bivariate.verify_point(b, &a.point(b.identifier).unwrap()));
+        }
+    }
+    assert_eq!(bivariate.combine_shares(&shares[..3]).unwrap(), secret);
+    assert_eq!(bivariate.combine_shares(&shares[2..]).unwrap(), secret);
+    let plain: Vec<Share> = shares.iter().map(|s| s.share().unwrap()).collect();
+    assert_eq!(
+        Shamir { t: 3, n: 5 }.combine_shares::<F>(&plain).unwrap(),
+        secret
+    );
+
+    // a corrupted polynomial is caught by its peers
+    let mut bad = shares[1].clone();
+    bad.coefficients[1] += F::one();
+    assert!(!shares[0].verify_point(&bad.point(1).unwrap()));
+    assert!(!bad.verify_point(&shares[0].point(2).unwrap()));
+    assert!(!shares[0].verify_point(&shares[2].point(2).unwrap()));
+
+    // a participant recovers its polynomial from its peers' points
+    let points: Vec<BivariatePoint<F>> = shares[1..4].iter().map(|s| s.point(1).unwrap()).collect();
+    assert_eq!(bivariate.recover_share(1, &points).unwrap(), shares[0]);
+    assert_eq!(
+        bivariate.recover_share(1, &points[..2]).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+    assert_eq!(
+        bivariate.recover_share(2, &points).unwrap_err(),
+        Error::SharingInvalidIdentifier
+    );
+
+    // a dealer can't use a higher degree symmetric polynomial
+    let over = Bivariate { t: 4, n: 5 }
+        .split_secret::<F, MockRng>(secret, &mut rng)
+        .unwrap();
+    for a in &over {
+        assert!(!bivariate.verify_share(a));
+        for b in &over {
+            // the pairwise checks alone still pass
+            assert!(b.verify_point(&a.point(b.identifier).unwrap()));
+            assert!(!bivariate.verify_point(b, &a.point(b.identifier).unwrap()));
+        }
+    }
+    // and different subsets would disagree on the secret
+    let plain: Vec<Share> = over.iter().map(|s| s.share().unwrap()).collect();
+    assert_ne!(
+        Shamir { t: 3, n: 5 }
+            .combine_shares::<F>(&plain[..3])
+            .unwrap(),
+        Shamir { t: 3, n: 5 }
+            .combine_shares::<F>(&plain[2..])
+            .unwrap()
+    );
+    assert_eq!(
+        bivariate.combine_shares(&over[..3]).unwrap_err(),
+        Error::InvalidShare
+    );
+    let points: Vec<BivariatePoint<F>> = over[1..].iter().map(|s| s.point(1).unwrap()).collect();
+    assert_eq!(
+        bivariate.recover_share(1, &points).unwrap_err(),
+        Error::InvalidShare
+    );
+
+    let res = serde_json::to_string(&shares[0]).unwrap();
+    assert_eq!(
+        serde_json::from_str::<BivariateShare<F>>(&res).unwrap(),
+        shares[0]
+    );
+    let res = serde_bare::to_vec(&shares[0]).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<BivariateShare<F>>(&res).unwrap(),
+        shares[0]
+    );
+}
+
+pub fn checked_shamir<F: PrimeField + Zeroize>() {
+    let mut rng = MockRng::default();
+    let scheme = CheckedShamir { t: 3, n: 5 };
+    let secret = F::random(&mut rng);
+    let shares = scheme.split_secret::<F, MockRng>(secret, &mut rng).unwrap();
+    for own in &shares {
+        for other in &shares {
+            let id = own.share.identifier();
+            if own.share == other.share {
+                assert_eq!(
+                    other.reveal_to(id).unwrap_err(),
+                    Error::SharingInvalidIdentifier
+                );
+            } else {
+                assert!(own.check(&other.reveal_to(id).unwrap()));
+            }
+        }
+    }
+    // each holder reveals only its share and the tag for that recipient
+    let reveals_to = |id: u8, from: &[CheckedShare<F>]| -> Vec<CheckedReveal<F>> {
+        from.iter().map(|s| s.reveal_to(id).unwrap()).collect()
+    };
+    assert_eq!(
+        scheme
+            .combine_shares(&shares[0], &reveals_to(1, &shares[1..3]))
+            .unwrap(),
+        secret
+    );
+    assert_eq!(
+        scheme
+            .combine_shares(&shares[4], &reveals_to(5, &shares[2..4]))
+            .unwrap(),
+        secret
+    );
+    // a reveal for another recipient doesn't check
+    assert_eq!(
+        scheme.culprits(&shares[0], &reveals_to(2, &shares[2..4])),
+        [3, 4]
+    );
+
+    // a modified share is caught
+    let mut bad = shares[2].reveal_to(1).unwrap();
+    bad.share = Share::from_field_element(3, bad.share.as_field_element::<F>().unwrap() + F::one())
+        .unwrap();
+    let others = [shares[1].reveal_to(1).unwrap(), bad];
+    assert_eq!(
+        scheme.combine_shares(&shares[0], &others).unwrap_err(),
+        Error::InvalidShare
+    );
+    assert_eq!(scheme.culprits(&shares[0], &others), [3]);
+    // so is a share presented with another holder's identifier
+    let mut bad = shares[2].reveal_to(1).unwrap();
+    bad.share.0[0] = 4;
+    assert_eq!(scheme.culprits(&shares[0], &[bad]), [4]);
+
+    // a rushing holder 3 that waits for everyone else's reveals
+    // learns their shares and tags but none of the keys checking its own
+    let seen = reveals_to(3, &[shares[0].clone(), shares[1].clone()]);
+    assert!(seen.iter().all(|r| shares[2].check(r)));
+    let forged = Share::from_field_element(
+        3,
+        shares[2].share.as_field_element::<F>().unwrap() + F::one(),
+    )
+    .unwrap();
+    let delta = seen[0].tag - seen[1].tag;
+    for tag in [
+        shares[2].tags[0],
+        shares[2].tags[0] + F::one(),
+        shares[2].tags[0] + delta,
+    ] {
+        let forged = CheckedReveal {
+            share: forged.clone(),
+            tag,
+        };
+        assert_eq!(
+            scheme.culprits(&shares[0], core::slice::from_ref(&forged)),
+            [3]
+        );
+        assert_eq!(
+            scheme
+                .combine_shares(&shares[0], &[seen[1].clone(), forged])
+                .unwrap_err(),
+            Error::InvalidShare
+        );
+    }
+
+    let res = serde_json::to_string(&shares[0]).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CheckedShare<F>>(&res).unwrap(),
+        shares[0]
+    );
+    let res = serde_bare::to_vec(&shares[0]).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<CheckedShare<F>>(&res).unwrap(),
+        shares[0]
+    );
+    let reveal = shares[0].reveal_to(2).unwrap();
+    let res = serde_bare::to_vec(&reveal).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<CheckedReveal<F>>(&res).unwrap(),
+        reveal
+    );
+}
+
+pub fn share_envelope<F: PrimeField + CurveIdentifier + Zeroize>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let shares = Shamir { t: 2, n: 3 }
+        .split_secret::<F, MockRng>(secret, &mut rng)
+        .unwrap();
+    let envelopes =
+        ShareEnvelope::wrap_all::<F, MockRng>(ShareScheme::Shamir, 2, &shares, &mut rng).unwrap();
+    assert_eq!(envelopes[0].curve, F::CURVE_ID);
+    assert_eq!(envelopes[1].open::<F>().unwrap(), shares[1]);
+    assert_eq!(
+        ShareEnvelope::combine::<F>(&envelopes[1..]).unwrap(),
+        secret
+    );
+
+    for envelope in &envelopes {
+        let bytes = envelope.to_bytes();
+        assert_eq!(&ShareEnvelope::from_bytes(&bytes).unwrap(), envelope);
+        let text = envelope.to_string();
+        assert!(text.starts_with(&format!("vsss1:shamir:{}:2:3:", F::CURVE_ID.name())));
+        assert_eq!(&text.parse::<ShareEnvelope>().unwrap(), envelope);
+
+        // any corruption fails the checksum
+        for i in 0..bytes.len() {
+            let mut tampered = bytes.clone();
+            tampered[i] ^= 1;
+            assert!(ShareEnvelope::from_bytes(&tampered).is_err());
+        }
+        let tampered = text.replacen(":2:3:", ":2:4:", 1);
+        assert_eq!(
+            tampered.parse::<ShareEnvelope>().unwrap_err(),
+            Error::InvalidEnvelope
+        );
+    }
+
+    // shares from another split are rejected
+    let others =
+        ShareEnvelope::wrap_all::<F, MockRng>(ShareScheme::Shamir, 2, &shares, &mut rng).unwrap();
+    assert_eq!(
+        ShareEnvelope::combine::<F>(&[envelopes[0].clone(), others[1].clone()]).unwrap_err(),
+        Error::InvalidEnvelope
+    );
+    let mut feldman = envelopes[1].clone();
+    feldman.scheme = ShareScheme::Feldman;
+    assert_eq!(
+        ShareEnvelope::combine::<F>(&[envelopes[0].clone(), feldman]).unwrap_err(),
+        Error::InvalidEnvelope
+    );
+
+    let res = serde_json::to_string(&envelopes[0]).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ShareEnvelope>(&res).unwrap(),
+        envelopes[0]
+    );
+    let res = serde_bare::to_vec(&envelopes[0]).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<ShareEnvelope>(&res).unwrap(),
+        envelopes[0]
+    );
+    let res = serde_cbor::to_vec(&envelopes[0]).unwrap();
+    assert_eq!(
+        serde_cbor::from_slice::<ShareEnvelope>(&res).unwrap(),
+        envelopes[0]
+    );
+}
+
+pub fn ramp_combine<F: PrimeField + Zeroize>() {
+    let mut rng = MockRng::default();
+    let ramp = Ramp { t: 2, r: 5, n: 7 };
+    let secret = [
+        F::random(&mut rng),
+        F::random(&mut rng),
+        F::random(&mut rng),
+    ];
+
+    assert!(ramp
+        .split_secret::<F, MockRng>(&secret[..2], &mut rng)
+        .is_err());
+    assert!(Ramp { t: 2, r: 2, n: 7 }
+        .split_secret::<F, MockRng>(&[], &mut rng)
+        .is_err());
+    assert!(Ramp { t: 2, r: 5, n: 4 }
+        .split_secret::<F, MockRng>(&secret, &mut rng)
+        .is_err());
+
+    let res = ramp.split_secret::<F, MockRng>(&secret, &mut rng);
+    assert!(res.is_ok());
+    let shares = res.unwrap();
+    assert_eq!(shares.len(), 7);
+
+    let res = ramp.combine_shares::<F>(&shares);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), secret);
+
+    let subset = [
+        shares[6].clone(),
+        shares[1].clone(),
+        shares[4].clone(),
+        shares[0].clone(),
+        shares[3].clone(),
+    ];
+    let res = ramp.combine_shares::<F>(&subset);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), secret);
+
+    assert!(ramp.combine_shares::<F>(&shares[..4]).is_err());
+    let dup = [
+        shares[0].clone(),
+        shares[1].clone(),
+        shares[2].clone(),
+        shares[3].clone(),
+        shares[0].clone(),
+    ];
+    assert!(ramp.combine_shares::<F>(&dup).is_err());
+}
+
+pub fn sum_of_products_matches<
+    F: PrimeField,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let mut points = [G::identity(); 70];
+    let mut scalars = [F::zero(); 70];
+    for (p, s) in points.iter_mut().zip(scalars.iter_mut()) {
+        *p = G::random(&mut rng);
+        *s = F::random(&mut rng);
+    }
+    scalars[1] = F::zero();
+    scalars[2] = -F::one();
+
+    for n in [0, 1, 3, 4, 15, 16, 64, 70] {
+        let expected = points[..n]
+            .iter()
+            .zip(scalars[..n].iter())
+            .fold(G::identity(), |acc, (p, s)| acc + *p * *s);
+        assert_eq!(sum_of_products(&points[..n], &scalars[..n]), expected);
+    }
+    // Mismatched lengths use the shorter
+    assert_eq!(
+        sum_of_products(&points[..5], &scalars[..4]),
+        sum_of_products(&points[..4], &scalars[..4])
+    );
+}
+
+pub fn verify_batch_culprits<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+
+    let (mut shares, verifier) = Feldman { t: 3, n: 6 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    assert!(verifier.verify_batch(&shares, &mut rng).is_ok());
+    assert!(verifier.verify_batch(&[], &mut rng).is_ok());
+    shares[1] = Share::from_field_element(2, F::random(&mut rng)).unwrap();
+    shares[4].0[0] = 1;
+    assert_eq!(verifier.verify_batch(&shares, &mut rng), Err(vec![1, 4]));
+
+    let mut res = Pedersen { t: 3, n: 6 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+    assert!(res
+        .verifier
+        .verify_batch(&res.secret_shares, &res.blind_shares, &mut rng)
+        .is_ok());
+    res.blind_shares[3] = Share::from_field_element(4, F::random(&mut rng)).unwrap();
+    assert_eq!(
+        res.verifier
+            .verify_batch(&res.secret_shares, &res.blind_shares, &mut rng),
+        Err(vec![3])
+    );
+    assert_eq!(
+        res.verifier
+            .verify_batch(&res.secret_shares, &res.blind_shares[..5], &mut rng),
+        Err(vec![3, 5])
+    );
+}
+
+pub fn public_shares_match<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let (shares, verifier) = Feldman { t: 3, n: 5 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+
+    let public_shares = verifier.public_shares(5);
+    assert_eq!(public_shares.len(), 5);
+    for (s, p) in shares.iter().zip(public_shares.iter()) {
+        let value = bytes_to_field::<F>(s.value()).unwrap();
+        assert_eq!(verifier.generator * value, *p);
+        assert_eq!(verifier.public_share(s.identifier()), *p);
+    }
+
+    let group_shares: Vec<Share> = public_shares
+        .iter()
+        .enumerate()
+        .map(|(i, p)| Share::from_group_element((i + 1) as u8, *p).unwrap())
+        .collect();
+    let public_key = Shamir { t: 3, n: 5 }
+        .combine_shares_group::<F, G>(&group_shares[1..4])
+        .unwrap();
+    assert_eq!(public_key, verifier.generator * secret);
+}
+
+pub fn aggregate_dealers<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let feldman = Feldman { t: 3, n: 5 };
+    let pedersen = Pedersen { t: 3, n: 5 };
+
+    let mut secret = F::zero();
+    let mut dealings = Vec::new();
+    let mut results = Vec::new();
+    for _ in 0..3 {
+        let s = F::random(&mut rng);
+        secret += s;
+        dealings.push(
+            feldman
+                .split_secret::<F, G, MockRng>(s, None, &mut rng)
+                .unwrap(),
+        );
+        results.push(
+            pedersen
+                .split_secret::<F, G, MockRng>(s, None, None, None, &mut rng)
+                .unwrap(),
+        );
+    }
+
+    let mut verifier = dealings[0].1.clone();
+    for (_, v) in &dealings[1..] {
+        verifier = (&verifier + v).unwrap();
+    }
+    let mut pedersen_verifier = results[0].verifier.clone();
+    for r in &results[1..] {
+        pedersen_verifier = (&pedersen_verifier + &r.verifier).unwrap();
+    }
+
+    let mut shares = Vec::new();
+    for i in 0..5 {
+        let received: Vec<Share> = dealings.iter().map(|(s, _)| s[i].clone()).collect();
+        let share = Share::sum_field_elements::<F>(&received).unwrap();
+        assert!(verifier.verify(&share));
+
+        let received: Vec<Share> = results.iter().map(|r| r.secret_shares[i].clone()).collect();
+        let secret_share = Share::sum_field_elements::<F>(&received).unwrap();
+        let received: Vec<Share> = results.iter().map(|r| r.blind_shares[i].clone()).collect();
+        let blind_share = Share::sum_field_elements::<F>(&received).unwrap();
+        assert!(pedersen_verifier.verify(&secret_share, &blind_share));
+
+        shares.push(share);
+    }
+    let res = Shamir { t: 3, n: 5 }.combine_shares::<F>(&shares[..3]);
+    assert_eq!(res.unwrap(), secret);
+
+    let mixed = [dealings[0].0[0].clone(), dealings[1].0[1].clone()];
+    assert_eq!(
+        Share::sum_field_elements::<F>(&mixed),
+        Err(Error::SharingInvalidIdentifier)
+    );
+
+    let (_, other) = feldman
+        .split_secret::<F, G, MockRng>(secret, Some(G::random(&mut rng)), &mut rng)
+        .unwrap();
+    assert_eq!((&verifier + &other), Err(Error::InvalidGenerator));
+    let (_, other) = Feldman { t: 2, n: 5 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    assert_eq!((verifier + other), Err(Error::SharingThresholdMismatch));
+}
+
+pub fn blinding_generator_checks<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let pedersen = Pedersen { t: 2, n: 3 };
+
+    let res = pedersen
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+    assert_eq!(res.verifier.generator, G::blinding_generator());
+    assert!(res.verifier.verify_blinding_generator());
+
+    let h = G::generator() * F::random(&mut rng);
+    let res = pedersen
+        .split_secret::<F, G, MockRng>(secret, None, None, Some(h), &mut rng)
+        .unwrap();
+    assert!(!res.verifier.verify_blinding_generator());
+}
+
+pub fn gjkr_dkg<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let mut parties: Vec<GjkrParticipant<F, G>> = (1..=5)
+        .map(|i| GjkrParticipant::new(i, 3, 5, None, None).unwrap())
+        .collect();
+
+    let mut broadcasts = Vec::new();
+    let mut p2p = Vec::new();
+    for p in parties.iter_mut() {
+        let (b, s) = p.round1(&mut rng).unwrap();
+        broadcasts.push(b);
+        p2p.extend(s);
+    }
+    // 2 and 3 send a bad share to 1, only 2 justifies it
+    for s in p2p.iter_mut() {
+        if (s.sender == 2 || s.sender == 3) && s.receiver == 1 {
+            s.secret_share = Share::from_field_element(1, F::random(&mut rng)).unwrap();
+        }
+    }
+
+    let mut complaints = Vec::new();
+    for p in parties.iter_mut() {
+        complaints.extend(p.receive_round1(&broadcasts, &p2p).unwrap());
+    }
+    assert_eq!(complaints.len(), 2);
+    let debug = format!("{:?}", parties[0]);
+    assert!(debug.starts_with("GjkrParticipant {"));
+    for s in p2p.iter().filter(|s| s.sender == 1 || s.receiver == 1) {
+        assert!(!debug.contains(&format!("{:?}", s.secret_share.value())));
+        assert!(!debug.contains(&format!("{:?}", s.blind_share.value())));
+    }
+    let mut justifications = Vec::new();
+    for p in parties.iter().filter(|p| p.id() != 3) {
+        justifications.extend(p.justify(&complaints).unwrap());
+    }
+    assert_eq!(justifications.len(), 1);
+    for p in parties.iter_mut() {
+        assert_eq!(
+            p.qualify(&complaints, &justifications).unwrap(),
+            vec![1, 2, 4, 5]
+        );
+    }
+
+    // 4 broadcasts Feldman commitments that don't match its shares
+    let mut broadcasts = Vec::new();
+    for p in &parties {
+        let mut b = p.round2().unwrap();
+        if b.sender == 4 {
+            b.verifier.commitments[1] += G::generator();
+        }
+        broadcasts.push(b);
+    }
+    let mut complaints = Vec::new();
+    for p in parties.iter_mut() {
+        complaints.extend(p.receive_round2(&broadcasts).unwrap());
+    }
+    assert_eq!(complaints.len(), 4);
+    let mut reconstruction = Vec::new();
+    for p in parties.iter_mut() {
+        reconstruction.extend(p.reconstruction_shares(&complaints).unwrap());
+    }
+    let outputs: Vec<_> = parties
+        .iter_mut()
+        .map(|p| p.complete(&reconstruction).unwrap())
+        .collect();
+
+    for o in &outputs {
+        assert_eq!(o.public_key, outputs[0].public_key);
+        assert!(!format!("{:?}", o).contains(&format!("{:?}", o.share.value())));
+        assert_eq!(o.public_shares, outputs[0].public_shares);
+        assert_eq!(o.qualified, vec![1, 2, 4, 5]);
+        let value = bytes_to_field::<F>(o.share.value()).unwrap();
+        assert_eq!(
+            G::generator() * value,
+            o.public_shares[o.share.identifier() as usize - 1]
+        );
+    }
+    let shares: Vec<Share> = outputs.iter().map(|o| o.share.clone()).collect();
+    let secret = Shamir { t: 3, n: 5 }
+        .combine_shares::<F>(&shares[2..])
+        .unwrap();
+    assert_eq!(G::generator() * secret, outputs[0].public_key);
+
+    assert_eq!(parties[0].round2().unwrap_err(), Error::InvalidRound);
+}
+
+pub fn frost_keygen<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let context = b"frost keygen test";
+    let mut parties: Vec<FrostKeygenParticipant<F, G>> = (1..=4)
+        .map(|i| FrostKeygenParticipant::new(i, 3, 4, context).unwrap())
+        .collect();
+
+    let broadcasts: Vec<_> = parties
+        .iter_mut()
+        .map(|p| p.round1(&mut rng).unwrap())
+        .collect();
+    for b in &broadcasts {
+        assert!(b.verify(context));
+        assert!(!b.verify(b"another context"));
+    }
+    let mut forged = broadcasts.clone();
+    forged[1].proof.response += F::one();
+    assert!(!forged[1].verify(context));
+    let mut party = parties[0].clone();
+    assert_eq!(
+        party.receive_round1(&forged).unwrap_err(),
+        Error::InvalidProof
+    );
+
+    let mut p2p = Vec::new();
+    for p in parties.iter_mut() {
+        p2p.extend(p.receive_round1(&broadcasts).unwrap());
+    }
+    let debug = format!("{:?}", parties[0]);
+    assert!(debug.starts_with("FrostKeygenParticipant {"));
+    for s in p2p.iter().filter(|s| s.sender == 1) {
+        assert!(!debug.contains(&format!("{:?}", s.share.value())));
+    }
+    let mut tampered = p2p.clone();
+    for s in tampered.iter_mut().filter(|s| s.receiver == 1) {
+        s.share = Share::from_field_element(1, F::random(&mut rng)).unwrap();
+    }
+    let mut party = parties[0].clone();
+    assert_eq!(
+        party.receive_round2(&tampered).unwrap_err(),
+        Error::InvalidShare
+    );
+
+    let packages: Vec<_> = parties
+        .iter_mut()
+        .map(|p| p.receive_round2(&p2p).unwrap())
+        .collect();
+    for k in &packages {
+        assert_eq!(k.verifying_key, packages[0].verifying_key);
+        assert_eq!(k.verifying_shares, packages[0].verifying_shares);
+        assert_eq!(G::generator() * k.signing_share, k.verifying_share);
+    }
+    let shares: Vec<Share> = packages
+        .iter()
+        .map(|k| Share::from_field_element(k.identifier, k.signing_share).unwrap())
+        .collect();
+    let secret = Shamir { t: 3, n: 4 }
+        .combine_shares::<F>(&shares[1..])
+        .unwrap();
+    assert_eq!(G::generator() * secret, packages[0].verifying_key);
+}
+
+pub fn sigma_proofs<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let g = G::generator();
+    let h = G::blinding_generator();
+    let x = F::random(&mut rng);
+    let y = F::random(&mut rng);
+
+    let context = b"sigma proofs test";
+
+    let proof = SchnorrProof::new(x, g, context, &mut rng);
+    assert!(proof.verify(g, g * x, context));
+    assert!(!proof.verify(g, g * y, context));
+    assert!(!proof.verify(h, h * x, context));
+    assert!(!proof.verify(g, g * x, b"another context"));
+    assert!(!proof.verify(g, g * x, &[]));
+    let res = serde_json::to_string(&proof).unwrap();
+    let proof2: SchnorrProof<F> = serde_json::from_str(&res).unwrap();
+    assert!(proof2.verify(g, g * x, context));
+    let res = serde_bare::to_vec(&proof).unwrap();
+    let proof2: SchnorrProof<F> = serde_bare::from_slice(&res).unwrap();
+    assert!(proof2.verify(g, g * x, context));
+
+    let proof = DleqProof::new(x, g, h, context, &mut rng);
+    assert!(proof.verify(g, g * x, h, h * x, context));
+    assert!(!proof.verify(g, g * x, h, h * y, context));
+    assert!(!proof.verify(g, g * x, h, h * x, b"another context"));
+    let res = serde_json::to_string(&proof).unwrap();
+    let proof2: DleqProof<F> = serde_json::from_str(&res).unwrap();
+    assert!(proof2.verify(g, g * x, h, h * x, context));
+
+    let commitment = g * x + h * y;
+    let proof = OkamotoProof::new(x, y, g, h, context, &mut rng);
+    assert!(proof.verify(g, h, commitment, context));
+    assert!(!proof.verify(g, h, commitment + g, context));
+    assert!(!proof.verify(h, g, commitment, context));
+    assert!(!proof.verify(g, h, commitment, b"another context"));
+    let res = serde_json::to_string(&proof).unwrap();
+    let proof2: OkamotoProof<F> = serde_json::from_str(&res).unwrap();
+    assert!(proof2.verify(g, h, commitment, context));
+    let res = serde_bare::to_vec(&proof).unwrap();
+    let proof2: OkamotoProof<F> = serde_bare::from_slice(&res).unwrap();
+    assert!(proof2.verify(g, h, commitment, context));
+}
+
+pub fn threshold_elgamal<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let (shares, verifier) = Feldman { t: 3, n: 5 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+
+    let x = F::random(&mut rng);
+    let proof = DleqProof::new(x, G::generator(), verifier.commitments[1], &[], &mut rng);
+    assert!(proof.verify(
+        G::generator(),
+        G::generator() * x,
+        verifier.commitments[1],
+        verifier.commitments[1] * x,
+        &[]
+    ));
+    assert!(!proof.verify(
+        G::generator(),
+        G::generator() * x,
+        verifier.commitments[1],
+        verifier.commitments[1] * (x + F::one()),
+        &[]
+    ));
+
+    // tally three yes votes and a no vote
+    let mut tally = ElGamalCiphertext::encrypt(G::identity(), &verifier, &mut rng).unwrap();
+    for vote in [1u64, 0, 1, 1] {
+        let ciphertext =
+            ElGamalCiphertext::encrypt(G::generator() * F::from(vote), &verifier, &mut rng)
+                .unwrap();
+        tally = tally + ciphertext;
+    }
+    let decryption_shares: Vec<_> = shares
+        .iter()
+        .map(|s| tally.decryption_share(s, &verifier, &mut rng).unwrap())
+        .collect();
+    for d in &decryption_shares {
+        assert!(tally.verify_decryption_share(d, &verifier).is_ok());
+    }
+    assert_eq!(
+        tally.decrypt(&decryption_shares[1..4], &verifier).unwrap(),
+        G::generator() * F::from(3u64)
+    );
+    assert_eq!(
+        tally
+            .decrypt(&decryption_shares[3..], &verifier)
+            .unwrap_err(),
+        Error::SharingMinThreshold
+    );
+
+    let mut tampered = decryption_shares.clone();
+    tampered[0].value += G::generator();
+    assert_eq!(
+        tally
+            .verify_decryption_share(&tampered[0], &verifier)
+            .unwrap_err(),
+        Error::InvalidProof
+    );
+    assert_eq!(
+        tally.decrypt(&tampered, &verifier).unwrap_err(),
+        Error::InvalidProof
+    );
+}
+
+pub fn pvss_deal_and_combine<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let pvss = Pvss { t: 3, n: 5 };
+    let secret_keys: Vec<F> = (0..5).map(|_| F::random(&mut rng)).collect();
+    let public_keys: Vec<G> = secret_keys.iter().map(|k| G::generator() * *k).collect();
+
+    let secret = F::random(&mut rng);
+    let dealing = pvss.deal(secret, &public_keys, &mut rng).unwrap();
+    assert!(pvss
+        .verify_dealing(&dealing, &public_keys, &mut rng)
+        .is_ok());
+    assert_eq!(
+        pvss.verify_dealing(&dealing, &public_keys[1..], &mut rng)
+            .unwrap_err(),
+        Error::InvalidShare
+    );
+
+    // a share that doesn't match its commitment
+    let mut bad = dealing.clone();
+    bad.encrypted_shares[2] += G::generator();
+    assert_eq!(
+        pvss.verify_dealing(&bad, &public_keys, &mut rng)
+            .unwrap_err(),
+        Error::InvalidProof
+    );
+
+    // consistent proofs for shares that aren't on a polynomial of degree < t
+    let h = G::hash_to_group(&[], PVSS_COMMITMENT_GENERATOR_DST).unwrap();
+    let s = F::random(&mut rng);
+    let mut bad = dealing.clone();
+    bad.commitments[4] = h * s;
+    bad.encrypted_shares[4] = public_keys[4] * s;
+    bad.proofs[4] = DleqProof::new(s, h, public_keys[4], &[5], &mut rng);
+    assert_eq!(
+        pvss.verify_dealing(&bad, &public_keys, &mut rng)
+            .unwrap_err(),
+        Error::InvalidShare
+    );
+
+    let decrypted: Vec<_> = secret_keys
+        .iter()
+        .enumerate()
+        .map(|(i, k)| Pvss::decrypt_share(i as u8 + 1, *k, &dealing, &mut rng).unwrap())
+        .collect();
+    for (d, k) in decrypted.iter().zip(public_keys.iter()) {
+        assert!(Pvss::verify_decrypted_share(d, k, &dealing).is_ok());
+    }
+    assert_eq!(
+        pvss.combine(&decrypted[2..], &dealing, &public_keys)
+            .unwrap(),
+        G::generator() * secret
+    );
+    assert_eq!(
+        pvss.combine(&decrypted[3..], &dealing, &public_keys)
+            .unwrap_err(),
+        Error::SharingMinThreshold
+    );
+
+    let mut tampered = decrypted.clone();
+    tampered[0].value += G::generator();
+    assert_eq!(
+        Pvss::verify_decrypted_share(&tampered[0], &public_keys[0], &dealing).unwrap_err(),
+        Error::InvalidProof
+    );
+    assert_eq!(
+        pvss.combine(&tampered, &dealing, &public_keys).unwrap_err(),
+        Error::InvalidProof
+    );
+}
+
+pub fn share_transport<
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+>() {
+    let mut rng = MockRng::default();
+    let secret_key = F::random(&mut rng);
+    let public_key = G::generator() * secret_key;
+    let secret = F::random(&mut rng);
+
+    let (shares, verifier) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    let aad = verifier.hash();
+    let encrypted =
+        EncryptedShare::encrypt::<F, MockRng>(&shares[1], &public_key, &aad, &mut rng).unwrap();
+    assert_eq!(encrypted.identifier, 2);
+    let share = encrypted.decrypt(&secret_key, &aad).unwrap();
+    assert_eq!(share, shares[1]);
+    assert!(verifier.verify(&share));
+
+    // another dealing, recipient, identifier or ciphertext is rejected
+    let (_, other) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    assert_ne!(other.hash(), aad);
+    assert_eq!(
+        encrypted.decrypt(&secret_key, &other.hash()).unwrap_err(),
+        Error::InvalidCiphertext
+    );
+    assert_eq!(
+        encrypted.decrypt(&F::random(&mut rng), &aad).unwrap_err(),
+        Error::InvalidCiphertext
+    );
+    let mut tampered = encrypted.clone();
+    tampered.identifier = 3;
+    assert_eq!(
+        tampered.decrypt(&secret_key, &aad).unwrap_err(),
+        Error::InvalidCiphertext
+    );
+    let mut tampered = encrypted.clone();
+    tampered.ciphertext[1] ^= 1;
+    assert_eq!(
+        tampered.decrypt(&secret_key, &aad).unwrap_err(),
+        Error::InvalidCiphertext
+    );
+
+    let res = Pedersen { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+    let aad = res.verifier.hash();
+    assert_ne!(aad, res.verifier.feldman_verifier.hash());
+    let encrypted = EncryptedShare::encrypt_pedersen::<F, MockRng>(
+        &res.secret_shares[0],
+        &res.blind_shares[0],
+        &public_key,
+        &aad,
+        &mut rng,
+    )
+    .unwrap();
+    let (share, blind_share) = encrypted.decrypt_pedersen(&secret_key, &aad).unwrap();
+    assert_eq!(share, res.secret_shares[0]);
+    assert_eq!(blind_share, res.blind_shares[0]);
+    assert!(res.verifier.verify(&share, &blind_share));
+    assert_eq!(
+        EncryptedShare::encrypt_pedersen::<F, MockRng>(
+            &res.secret_shares[0],
+            &res.blind_shares[1],
+            &public_key,
+            &aad,
+            &mut rng,
+        )
+        .unwrap_err(),
+        Error::InvalidShare
+    );
+}
+
+pub fn dealer_signatures<F, G, S, V>(signing_key: &S, verifying_key: &V)
+where
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    S: DealerSigningKey,
+    V: DealerVerifyingKey,
+{
+    let mut rng = MockRng::default();
+    let secret_key = F::random(&mut rng);
+    let public_key = G::generator() * secret_key;
+    let secret = F::random(&mut rng);
+
+    let (shares, verifier) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    let signature = DealerSignature::sign_feldman(&verifier, signing_key);
+    assert!(signature.verify_feldman(&verifier, verifying_key).is_ok());
+
+    // swapped commitments are rejected
+    let (_, other) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    assert_eq!(
+        signature.verify_feldman(&other, verifying_key).unwrap_err(),
+        Error::InvalidSignature
+    );
+    let mut tampered = signature.clone();
+    tampered.0[0] ^= 1;
+    assert_eq!(
+        tampered
+            .verify_feldman(&verifier, verifying_key)
+            .unwrap_err(),
+        Error::InvalidSignature
+    );
+
+    let aad = verifier.hash();
+    let encrypted =
+        EncryptedShare::encrypt::<F, MockRng>(&shares[0], &public_key, &aad, &mut rng).unwrap();
+    let signed = SignedShare::new(encrypted, public_key, aad, signing_key);
+    assert!(signed.verify(&aad, verifying_key).is_ok());
+    assert_eq!(
+        signed.verify(&other.hash(), verifying_key).unwrap_err(),
+        Error::InvalidSignature
+    );
+    let mut tampered = signed.clone();
+    tampered.share.identifier = 2;
+    assert_eq!(
+        tampered.verify(&aad, verifying_key).unwrap_err(),
+        Error::InvalidSignature
+    );
+    let mut tampered = signed.clone();
+    tampered.share.ciphertext[0] ^= 1;
+    assert_eq!(
+        tampered.verify(&aad, verifying_key).unwrap_err(),
+        Error::InvalidSignature
+    );
+    let mut tampered = signed.clone();
+    tampered.recipient = G::generator() * F::random(&mut rng);
+    assert_eq!(
+        tampered.verify(&aad, verifying_key).unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(signed.share.decrypt(&secret_key, &aad).unwrap(), shares[0]);
+
+    let res = Pedersen { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+    let signature = DealerSignature::sign_pedersen(&res.verifier, signing_key);
+    assert!(signature
+        .verify_pedersen(&res.verifier, verifying_key)
+        .is_ok());
+    // a Feldman signature can't be passed off as a Pedersen one
+    let feldman = DealerSignature::sign_feldman(&res.verifier.feldman_verifier, signing_key);
+    assert_eq!(
+        feldman
+            .verify_pedersen(&res.verifier, verifying_key)
+            .unwrap_err(),
+        Error::InvalidSignature
+    );
+}
+
+pub fn share_complaints<F, G, S, V>(signing_key: &S, verifying_key: &V)
+where
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+    S: DealerSigningKey,
+    V: DealerVerifyingKey,
+{
+    let mut rng = MockRng::default();
+    let secret_key = F::random(&mut rng);
+    let public_key = G::generator() * secret_key;
+    let secret = F::random(&mut rng);
+
+    let (shares, verifier) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    let hash = verifier.hash();
+    let bad_share =
+        Share::from_field_element(1, shares[0].as_field_element::<F>().unwrap() + F::one())
+            .unwrap();
+    assert!(!verifier.verify(&bad_share));
+
+    // signed plaintext shares
+    let signature = DealerSignature::sign_plaintext_share(&bad_share, None, &hash, signing_key);
+    let complaint =
+        ShareComplaint::<F, G>::from_signed_share(bad_share.clone(), None, signature, hash);
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let res = serde_json::to_string(&complaint).unwrap();
+    let complaint2: ShareComplaint<F, G> = serde_json::from_str(&res).unwrap();
+    assert_eq!(
+        complaint2.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let signature = DealerSignature::sign_plaintext_share(&shares[0], None, &hash, signing_key);
+    let complaint =
+        ShareComplaint::<F, G>::from_signed_share(bad_share.clone(), None, signature.clone(), hash);
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+    let complaint =
+        ShareComplaint::<F, G>::from_signed_share(shares[0].clone(), None, signature, hash);
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+
+    // opened share envelopes
+    let envelope = |share: &Share, rng: &mut MockRng| {
+        let encrypted =
+            EncryptedShare::encrypt::<F, MockRng>(share, &public_key, &hash, rng).unwrap();
+        SignedShare::new(encrypted, public_key, hash, signing_key)
+    };
+    let bad_envelope = envelope(&bad_share, &mut rng);
+    let complaint =
+        ShareComplaint::from_envelope(bad_envelope.clone(), &secret_key, &mut rng).unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let res = serde_bare::to_vec(&complaint).unwrap();
+    let complaint2: ShareComplaint<F, G> = serde_bare::from_slice(&res).unwrap();
+    assert_eq!(
+        complaint2.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let (_, other) = Feldman { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, &mut rng)
+        .unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&other, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+    let good_envelope = envelope(&shares[0], &mut rng);
+    let complaint =
+        ShareComplaint::from_envelope(good_envelope.clone(), &secret_key, &mut rng).unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+    // the envelope binds the recipient key
+    let other_key = F::random(&mut rng);
+    assert_eq!(
+        ShareComplaint::from_envelope(bad_envelope, &other_key, &mut rng).unwrap_err(),
+        Error::InvalidCiphertext
+    );
+    let mut forged = good_envelope;
+    forged.recipient = G::generator() * other_key;
+    let complaint = ShareComplaint::from_envelope(forged, &other_key, &mut rng).unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+
+    // a dealer can't escape by signing an envelope the recipient can't decrypt
+    let other_public_key = G::generator() * other_key;
+    let encrypted =
+        EncryptedShare::encrypt::<F, MockRng>(&shares[0], &other_public_key, &hash, &mut rng)
+            .unwrap();
+    let undecryptable = SignedShare::new(encrypted, public_key, hash, signing_key);
+    let complaint = ShareComplaint::from_envelope(undecryptable, &secret_key, &mut rng).unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let mut encrypted =
+        EncryptedShare::encrypt::<F, MockRng>(&shares[0], &public_key, &hash, &mut rng).unwrap();
+    encrypted.ciphertext[0] ^= 1;
+    let garbled = SignedShare::new(encrypted, public_key, hash, signing_key);
+    let complaint = ShareComplaint::from_envelope(garbled, &secret_key, &mut rng).unwrap();
+    assert_eq!(
+        complaint.judge_feldman(&verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+
+    // Pedersen dealings
+    let res = Pedersen { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+    let hash = res.verifier.hash();
+    let bad_blind = Share::from_field_element(
+        1,
+        res.blind_shares[0].as_field_element::<F>().unwrap() + F::one(),
+    )
+    .unwrap();
+    let signature = DealerSignature::sign_plaintext_share(
+        &res.secret_shares[0],
+        Some(&bad_blind),
+        &hash,
+        signing_key,
+    );
+    let complaint = ShareComplaint::<F, G>::from_signed_share(
+        res.secret_shares[0].clone(),
+        Some(bad_blind.clone()),
+        signature,
+        hash,
+    );
+    assert_eq!(
+        complaint.judge_pedersen(&res.verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    assert_eq!(
+        complaint.judge_feldman(&res.verifier.feldman_verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+    let encrypted = EncryptedShare::encrypt_pedersen::<F, MockRng>(
+        &res.secret_shares[0],
+        &bad_blind,
+        &public_key,
+        &hash,
+        &mut rng,
+    )
+    .unwrap();
+    let complaint = ShareComplaint::from_envelope(
+        SignedShare::new(encrypted, public_key, hash, signing_key),
+        &secret_key,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        complaint.judge_pedersen(&res.verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+    let encrypted = EncryptedShare::encrypt_pedersen::<F, MockRng>(
+        &res.secret_shares[0],
+        &res.blind_shares[0],
+        &public_key,
+        &hash,
+        &mut rng,
+    )
+    .unwrap();
+    let complaint = ShareComplaint::from_envelope(
+        SignedShare::new(encrypted, public_key, hash, signing_key),
+        &secret_key,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        complaint.judge_pedersen(&res.verifier, verifying_key),
+        ComplaintVerdict::Dismissed
+    );
+    // an envelope that decrypts but doesn't hold a share and blind share
+    let encrypted =
+        EncryptedShare::encrypt::<F, MockRng>(&res.secret_shares[0], &public_key, &hash, &mut rng)
+            .unwrap();
+    let complaint = ShareComplaint::from_envelope(
+        SignedShare::new(encrypted, public_key, hash, signing_key),
+        &secret_key,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        complaint.judge_pedersen(&res.verifier, verifying_key),
+        ComplaintVerdict::Upheld
+    );
+}
+
+pub fn frost_key_packages<C: FrostCiphersuite>(
+    threshold: usize,
+    limit: u8,
+) -> Vec<FrostKeyPackage<C::Scalar, C::Group>> {
+    let mut rng = MockRng::default();
+    let mut parties: Vec<FrostKeygenParticipant<C::Scalar, C::Group>> = (1..=limit)
+        .map(|i| FrostKeygenParticipant::new(i, threshold, limit as usize, b"frost test").unwrap())
+        .collect();
+    let broadcasts: Vec<_> = parties
+        .iter_mut()
+        .map(|p| p.round1(&mut rng).unwrap())
+        .collect();
+    let mut p2p = Vec::new();
+    for p in parties.iter_mut() {
+        p2p.extend(p.receive_round1(&broadcasts).unwrap());
+    }
+    parties
+        .iter_mut()
+        .map(|p| p.receive_round2(&p2p).unwrap())
+        .collect()
+}
+
+pub fn frost_sign<C: FrostCiphersuite>() {
+    let mut rng = MockRng::default();
+    let packages = frost_key_packages::<C>(3, 4);
+    let verifying_key = packages[0].verifying_key;
+    let verifying_shares = &packages[0].verifying_shares;
+
+    let message = b"frost sign test message";
+    let signers = [&packages[3], &packages[0], &packages[2]];
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+        .iter()
+        .map(|k| Frost::<C>::commit(k, &mut rng))
+        .unzip();
+
+    // secrets are left out of the debug output
+    assert_eq!(format!("{:?}", nonces[0]), "FrostSigningNonces { .. }");
+    let signing_share = format!("{:?}", signers[0].signing_share);
+    assert!(!format!("{:?}", signers[0]).contains(&signing_share));
+
+    // the wrong nonces can't be used
+    let (other_nonces, _) = Frost::<C>::commit(signers[1], &mut rng);
+    assert_eq!(
+        Frost::<C>::sign(signers[0], other_nonces, &commitments, message).unwrap_err(),
+        Error::InvalidSignature
+    );
+    // not enough signers
+    let (other_nonces, _) = Frost::<C>::commit(signers[0], &mut rng);
+    assert_eq!(
+        Frost::<C>::sign(signers[0], other_nonces, &commitments[..2], message).unwrap_err(),
+        Error::SharingMinThreshold
+    );
+
+    let shares: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(k, n)| Frost::<C>::sign(k, n, &commitments, message).unwrap())
+        .collect();
+    for s in &shares {
+        assert!(Frost::<C>::verify_signature_share(
+            s,
+            &verifying_shares[s.identifier as usize - 1],
+            &commitments,
+            message,
+            &verifying_key
+        )
+        .is_ok());
+    }
+    let signature = Frost::<C>::aggregate(
+        &commitments,
+        message,
+        &shares,
+        &verifying_key,
+        verifying_shares,
+    )
+    .unwrap();
+    assert!(Frost::<C>::verify(&verifying_key, message, &signature).is_ok());
+    assert_eq!(
+        Frost::<C>::verify(&verifying_key, b"another message", &signature).unwrap_err(),
+        Error::InvalidSignature
+    );
+
+    let mut tampered = shares.clone();
+    tampered[1].share += C::Scalar::one();
+    assert_eq!(
+        Frost::<C>::verify_signature_share(
+            &tampered[1],
+            &verifying_shares[tampered[1].identifier as usize - 1],
+            &commitments,
+            message,
+            &verifying_key
+        )
+        .unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(
+        Frost::<C>::aggregate(
+            &commitments,
+            message,
+            &tampered,
+            &verifying_key,
+            verifying_shares
+        )
+        .unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(
+        Frost::<C>::aggregate(
+            &commitments,
+            message,
+            &shares[1..],
+            &verifying_key,
+            verifying_shares
+        )
+        .unwrap_err(),
+        Error::InvalidSignature
+    );
+}
+
+/// A signer's values from an RFC 9591 appendix E test vector
+pub struct FrostSignerVector {
+    pub identifier: u8,
+    pub hiding_nonce_randomness: &'static str,
+    pub binding_nonce_randomness: &'static str,
+    pub hiding_nonce: &'static str,
+    pub binding_nonce: &'static str,
+    pub hiding_nonce_commitment: &'static str,
+    pub binding_nonce_commitment: &'static str,
+    pub binding_factor: &'static str,
+    pub sig_share: &'static str,
+}
+
+/// An RFC 9591 appendix E test vector for a 2 of 3 key signed by participants 1 and 3
+pub struct FrostVector {
+    pub group_secret_key: &'static str,
+    pub group_public_key: &'static str,
+    pub share_polynomial_coefficient: &'static str,
+    pub participant_shares: [&'static str; 3],
+    pub signers: [FrostSignerVector; 2],
+    pub sig: &'static str,
+}
+
+/// Check key generation, nonces, commitments, binding factors, the group
+/// commitment, signature shares and the signature against RFC 9591 appendix E
+pub fn frost_rfc9591<C: FrostCiphersuite>(vector: &FrostVector) {
+    fn scalar<F: PrimeField>(s: &str) -> F {
+        let mut repr = F::Repr::default();
+        repr.as_mut().copy_from_slice(&hex::decode(s).unwrap());
+        F::from_repr(repr).unwrap()
+    }
+    fn randomness(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(s).unwrap());
+        out
+    }
+    let message = b"test";
+
+    let secret = scalar::<C::Scalar>(vector.group_secret_key);
+    let coefficient = scalar::<C::Scalar>(vector.share_polynomial_coefficient);
+    let verifying_key = C::Group::generator() * secret;
+    assert_eq!(
+        hex::encode(verifying_key.to_bytes()),
+        vector.group_public_key
+    );
+    let signing_shares: Vec<C::Scalar> = (1..=3u64)
+        .map(|i| secret + coefficient * C::Scalar::from(i))
+        .collect();
+    for (share, expected) in signing_shares.iter().zip(vector.participant_shares.iter()) {
+        assert_eq!(hex::encode(share.to_repr()), *expected);
+    }
+    let verifying_shares: Vec<C::Group> = signing_shares
+        .iter()
+        .map(|s| C::Group::generator() * *s)
+        .collect();
+    let packages: Vec<FrostKeyPackage<C::Scalar, C::Group>> = vector
+        .signers
+        .iter()
+        .map(|v| FrostKeyPackage {
+            identifier: v.identifier,
+            threshold: 2,
+            signing_share: signing_shares[v.identifier as usize - 1],
+            verifying_share: verifying_shares[v.identifier as usize - 1],
+            verifying_key,
+            verifying_shares: verifying_shares.clone(),
+        })
+        .collect();
+
+    // round one
+    let mut nonces = Vec::new();
+    let mut commitments = Vec::new();
+    for (v, package) in vector.signers.iter().zip(packages.iter()) {
+        let hiding_randomness = randomness(v.hiding_nonce_randomness);
+        let binding_randomness = randomness(v.binding_nonce_randomness);
+        let hiding = nonce_generate::<C>(&hiding_randomness, &package.signing_share);
+        let binding = nonce_generate::<C>(&binding_randomness, &package.signing_share);
+        assert_eq!(hex::encode(hiding.to_repr()), v.hiding_nonce);
+        assert_eq!(hex::encode(binding.to_repr()), v.binding_nonce);
+
+        let commitment = FrostSigningCommitments {
+            identifier: v.identifier,
+            hiding: C::Group::generator() * hiding,
+            binding: C::Group::generator() * binding,
+        };
+        assert_eq!(
+            hex::encode(commitment.hiding.to_bytes()),
+            v.hiding_nonce_commitment
+        );
+        assert_eq!(
+            hex::encode(commitment.binding.to_bytes()),
+            v.binding_nonce_commitment
+        );
+        nonces.push(FrostSigningNonces::from_randomness::<C>(
+            &hiding_randomness,
+            &binding_randomness,
+            &package.signing_share,
+        ));
+        commitments.push(commitment);
+    }
+
+    let session = SigningSession::<C>::new(&commitments, message, &verifying_key).unwrap();
+    for (v, binding_factor) in vector.signers.iter().zip(session.binding_factors.iter()) {
+        assert_eq!(hex::encode(binding_factor.to_repr()), v.binding_factor);
+    }
+    let group_commitment = hex::encode(session.group_commitment.to_bytes());
+    assert!(vector.sig.starts_with(&group_commitment));
+
+    // round two
+    let shares: Vec<_> = packages
+        .iter()
+        .zip(nonces)
+        .map(|(package, n)| Frost::<C>::sign(package, n, &commitments, message).unwrap())
+        .collect();
+    for (v, share) in vector.signers.iter().zip(shares.iter()) {
+        assert_eq!(hex::encode(share.share.to_repr()), v.sig_share);
+    }
+    let signature = Frost::<C>::aggregate(
+        &commitments,
+        message,
+        &shares,
+        &verifying_key,
+        &verifying_shares,
+    )
+    .unwrap();
+    assert_eq!(hex::encode(signature.to_bytes()), vector.sig);
+    assert!(Frost::<C>::verify(&verifying_key, message, &signature).is_ok());
+}
+
+pub fn bech32_encoding<F, G>()
+where
+    F: PrimeField + CurveIdentifier + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup + CurveIdentifier,
+{
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let res = Pedersen { t: 2, n: 3 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+
+    let share = &res.secret_shares[0];
+    let text = share.to_bech32::<F>();
+    assert!(text.starts_with("vsss"));
+    assert_eq!(&Share::from_bech32::<F>(&text).unwrap(), share);
+    assert_eq!(
+        &Share::from_bech32::<F>(&text.to_ascii_uppercase()).unwrap(),
+        share
+    );
+
+    // a single mistyped character or swapped neighbours fail the checksum
+    let data = text.rfind('1').unwrap() + 1;
+    for i in data..text.len() {
+        let mut typo = text.clone().into_bytes();
+        typo[i] = if typo[i] == b'q' { b'p' } else { b'q' };
+        let typo = String::from_utf8(typo).unwrap();
+        assert_eq!(
+            Share::from_bech32::<F>(&typo).unwrap_err(),
+            Error::InvalidEncoding
+        );
+    }
+    for i in data..text.len() - 1 {
+        let mut swapped = text.clone().into_bytes();
+        if swapped[i] == swapped[i + 1] {
+            continue;
+        }
+        swapped.swap(i, i + 1);
+        let swapped = String::from_utf8(swapped).unwrap();
+        assert!(Share::from_bech32::<F>(&swapped).is_err());
+    }
+    // the feldman prefix is rejected for a share
+    let feldman = res.verifier.feldman_verifier.to_bech32();
+    assert!(feldman.starts_with("vsssfv"));
+    assert!(Share::from_bech32::<F>(&feldman).is_err());
+
+    let decoded = FeldmanVerifier::<F, G>::from_bech32(&feldman).unwrap();
+    assert_eq!(decoded, res.verifier.feldman_verifier);
+    assert!(decoded.verify(share));
+
+    let pedersen = res.verifier.to_bech32();
+    assert!(pedersen.starts_with("vssspv"));
+    let decoded = PedersenVerifier::<F, G>::from_bech32(&pedersen).unwrap();
+    assert_eq!(decoded.hash(), res.verifier.hash());
+    assert!(decoded.verify(share, &res.blind_shares[0]));
+    assert!(PedersenVerifier::<F, G>::from_bech32(&feldman).is_err());
+    assert!(FeldmanVerifier::<F, G>::from_bech32(&pedersen).is_err());
+
+    // BIP-350 test vectors with a whole number of data bytes
+    for valid in [
+        "A1LQFN3A",
+        "a1lqfn3a",
+        "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+        "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+        "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+        "?1v759aa",
+    ] {
+        let lower = valid.to_ascii_lowercase();
+        let hrp = &lower[..lower.rfind('1').unwrap()];
+        let data = bech32::decode(hrp, valid).unwrap();
+        assert_eq!(bech32::encode(hrp, &data), lower);
+    }
+    assert_eq!(
+        bech32::decode("abcdef", "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx").unwrap(),
+        [
+            0xff, 0xbb, 0xcd, 0xeb, 0x38, 0xbd, 0xab, 0x49, 0xca, 0x30, 0x7b, 0x9a, 0xc5, 0xa9,
+            0x28, 0x39, 0x8a, 0x41, 0x88, 0x20
+        ]
+    );
+    for (hrp, invalid) in [
+        // HRP character out of range
+        (" ", " 1xj0phk"),
+        ("\x7f", "\x7f1g6xzxy"),
+        // no separator
+        ("", "qyrz8wqd2c9m"),
+        // empty HRP
+        ("", "1qyrz8wqd2c9m"),
+        ("", "16plkw9"),
+        ("", "1p2gdwpf"),
+        // invalid data character
+        ("y", "y1b0jsk6g"),
+        ("lt", "lt1igcx5c0"),
+        // too short checksum
+        ("in", "in1muywd"),
+        // invalid character in checksum
+        ("mm", "mm1crxm3i"),
+        ("au", "au1s5cgom"),
+        // checksum calculated with uppercase HRP
+        ("m", "M1VUXWEZ"),
+        // mixed case
+        ("a", "A1lqfn3a"),
+        ("abcdef", "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3RYX"),
+        // invalid checksum
+        ("a", "a1lqfn3q"),
+        ("abcdef", "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryz"),
+        // a valid BIP-173 bech32 checksum
+        ("a", "a12uel5l"),
+        ("abcdef", "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"),
+    ] {
+        assert_eq!(
+            bech32::decode(hrp, invalid).unwrap_err(),
+            Error::InvalidEncoding,
+            "{}",
+            invalid
+        );
+    }
+}
+
+pub fn der_encoding<F, G>()
+where
+    F: PrimeField + CurveIdentifier + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup + CurveIdentifier,
+{
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let res = Pedersen { t: 3, n: 5 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+
+    let share = &res.secret_shares[2];
+    let der = share.to_der::<F>().unwrap();
+    // SEQUENCE { INTEGER 1, OBJECT IDENTIFIER, ... }
+    assert_eq!(der[0], 0x30);
+    assert_eq!(&der[2..5], &[0x02, 0x01, 0x01]);
+    assert_eq!(der[5], 0x06);
+    assert_eq!(&Share::from_der::<F>(&der).unwrap(), share);
+    let pem = share.to_pem::<F>().unwrap();
+    assert!(pem.starts_with("-----BEGIN VSSS SHARE-----\n"));
+    assert!(pem.trim_end().ends_with("-----END VSSS SHARE-----"));
+    assert_eq!(&Share::from_pem::<F>(&pem).unwrap(), share);
+    let mut bad = der.clone();
+    bad[4] = 2;
+    assert_eq!(
+        Share::from_der::<F>(&bad).unwrap_err(),
+        Error::InvalidEncoding
+    );
+    assert!(Share::from_der::<F>(&der[..der.len() - 1]).is_err());
+
+    let feldman = &res.verifier.feldman_verifier;
+    let der = feldman.to_der().unwrap();
+    let decoded = FeldmanVerifier::<F, G>::from_der(&der).unwrap();
+    assert_eq!(&decoded, feldman);
+    assert!(decoded.verify(share));
+    let pem = feldman.to_pem().unwrap();
+    assert!(pem.starts_with("-----BEGIN VSSS FELDMAN VERIFIER-----\n"));
+    assert_eq!(&FeldmanVerifier::<F, G>::from_pem(&pem).unwrap(), feldman);
+    // the label must match the type
+    assert!(PedersenVerifier::<F, G>::from_pem(&pem).is_err());
+    assert!(Share::from_pem::<F>(&pem).is_err());
+
+    let der = res.verifier.to_der().unwrap();
+    let decoded = PedersenVerifier::<F, G>::from_der(&der).unwrap();
+    assert_eq!(decoded.hash(), res.verifier.hash());
+    assert!(decoded.verify(share, &res.blind_shares[2]));
+    let pem = res.verifier.to_pem().unwrap();
+    assert!(pem.starts_with("-----BEGIN VSSS PEDERSEN VERIFIER-----\n"));
+    let decoded = PedersenVerifier::<F, G>::from_pem(&pem).unwrap();
+    assert_eq!(decoded.hash(), res.verifier.hash());
+    assert!(FeldmanVerifier::<F, G>::from_der(&der).is_err());
+}
+
+pub fn byte_codec<F, G>()
+where
+    F: PrimeField + Zeroize,
+    G: Group + GroupEncoding + Default + ScalarMul<F> + HashToGroup,
+{
+    let mut rng = MockRng::default();
+    let secret = F::random(&mut rng);
+    let res = Pedersen { t: 3, n: 5 }
+        .split_secret::<F, G, MockRng>(secret, None, None, None, &mut rng)
+        .unwrap();
+
+    let share = &res.secret_shares[0];
+    let bytes = share.to_bytes();
+    assert_eq!(bytes.len(), share.bytes_len());
+    assert_eq!(&Share::from_bytes(&bytes).unwrap(), share);
+    assert_eq!(
+        Share::from_bytes(&[0u8; 33]).unwrap_err(),
+        Error::InvalidEncoding
+    );
+
+    let feldman = &res.verifier.feldman_verifier;
+    let bytes = feldman.to_bytes().unwrap();
+    let g_len = G::Repr::default().as_ref().len();
+    assert_eq!(bytes.len(), 1 + 4 * g_len);
+    assert_eq!(bytes[0], 3);
+    assert_eq!(
+        &FeldmanVerifier::<F, G>::from_bytes(&bytes).unwrap(),
+        feldman
+    );
+    // the slice variant writes the same bytes and rejects short buffers
+    let mut out = [0u8; 1024];
+    let len = feldman.to_bytes_into(&mut out).unwrap();
+    assert_eq!(&out[..len], &bytes[..]);
+    assert_eq!(
+        feldman.to_bytes_into(&mut out[..len - 1]).unwrap_err(),
+        Error::InvalidEncoding
+    );
+    assert!(FeldmanVerifier::<F, G>::from_bytes(&bytes[..len - 1]).is_err());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(FeldmanVerifier::<F, G>::from_bytes(&trailing).is_err());
+
+    let bytes = res.verifier.to_bytes().unwrap();
+    assert_eq!(bytes.len(), 1 + 8 * g_len);
+    let decoded = PedersenVerifier::<F, G>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.hash(), res.verifier.hash());
+
+    let bytes = res.to_bytes().unwrap();
+    assert_eq!(bytes.len(), res.bytes_len());
+    let decoded = PedersenResult::<F, G>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.blinding, res.blinding);
+    assert_eq!(decoded.secret_shares, res.secret_shares);
+    assert_eq!(decoded.blind_shares, res.blind_shares);
+    assert_eq!(decoded.verifier.hash(), res.verifier.hash());
+    for (s, b) in decoded.secret_shares.iter().zip(&decoded.blind_shares) {
+        assert!(decoded.verifier.verify(s, b));
+    }
+    // the byte layout is independent of the serde format
+    let json: PedersenResult<F, G> =
+        serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    let cbor: PedersenResult<F, G> =
+        serde_cbor::from_slice(&serde_cbor::to_vec(&res).unwrap()).unwrap();
+    assert_eq!(json.to_bytes().unwrap(), bytes);
+    assert_eq!(cbor.to_bytes().unwrap(), bytes);
+}
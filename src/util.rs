@@ -2,18 +2,23 @@
     Copyright Michael Lodder. All Rights Reserved.
     SPDX-License-Identifier: Apache-2.0
 */
+use crate::Error as SharingError;
 use core::{
     fmt::{self, Formatter},
     marker::PhantomData,
 };
+#[cfg(any(feature = "std", feature = "alloc"))]
+use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
 use elliptic_curve::{
     ff::PrimeField,
-    group::{Group, GroupEncoding},
+    group::{Group, GroupEncoding, ScalarMul},
 };
 use serde::{
     de::{Error, SeqAccess, Unexpected, Visitor},
     Deserializer,
 };
+#[cfg(any(feature = "std", feature = "alloc"))]
+use sha2_10::Sha256;
 
 pub fn bytes_to_field<F: PrimeField>(bytes: &[u8]) -> Option<F> {
     let mut s_repr = F::Repr::default();
@@ -39,6 +44,147 @@ pub fn bytes_to_group<G: Group + GroupEncoding>(bytes: &[u8]) -> Option<G> {
     }
 }
 
+/// Hash `msgs` to a field element as in RFC 9380 hash_to_field
+/// with expand_message_xmd using SHA-256.
+///
+/// `dst` must not be empty and is expected to be a constant.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub(crate) fn hash_to_field<F: PrimeField>(msgs: &[&[u8]], dst: &[u8]) -> F {
+    // enough bytes for 128 bits of security after the modular reduction
+    let len = (F::NUM_BITS as usize + 128 + 7) / 8;
+    let mut okm = [0u8; 128];
+    ExpandMsgXmd::<Sha256>::expand_message(msgs, dst, len)
+        .expect("a constant non-empty DST")
+        .fill_bytes(&mut okm[..len]);
+
+    // big endian reduction that works for any prime field
+    let radix = F::from(256);
+    let mut out = F::zero();
+    for b in &okm[..len] {
+        out *= radix;
+        out += F::from(*b as u64);
+    }
+    out
+}
+
+/// Compute the sum of products `points[0] * scalars[0] + points[1] * scalars[1] + ...`
+/// as a single multi-scalar multiplication using the bucket method from
+/// <https://cr.yp.to/papers/pippenger.pdf>, which saves most of the doublings
+/// and additions of the individual multiplications.
+///
+/// This is variable time in the scalars and must only be used when they are public
+/// like the powers of share identifiers or lagrange coefficients.
+/// Extra points or scalars beyond the shorter of the two slices are ignored.
+pub fn sum_of_products<F, G>(points: &[G], scalars: &[F]) -> G
+where
+    F: PrimeField,
+    G: Group + ScalarMul<F>,
+{
+    const MAX_WINDOW: usize = 6;
+
+    let limit = core::cmp::min(points.len(), scalars.len());
+    let window = match limit {
+        0..=3 => {
+            return points
+                .iter()
+                .zip(scalars.iter())
+                .fold(G::identity(), |acc, (p, s)| acc + *p * *s)
+        }
+        4..=15 => 3,
+        16..=63 => 4,
+        64..=255 => 5,
+        _ => MAX_WINDOW,
+    };
+    // Reprs are big endian for some fields and little endian for others
+    let little_endian = F::one().to_repr().as_ref()[0] == 1;
+    let num_bits = F::NUM_BITS as usize;
+    let num_buckets = (1 << window) - 1;
+
+    let mut buckets = [G::identity(); (1 << MAX_WINDOW) - 1];
+    let mut result = G::identity();
+    let mut offset = (num_bits + window - 1) / window * window;
+    while offset > 0 {
+        offset -= window;
+        for _ in 0..window {
+            result = result.double();
+        }
+
+        for b in buckets.iter_mut().take(num_buckets) {
+            *b = G::identity();
+        }
+        for (p, s) in points.iter().zip(scalars.iter()) {
+            let repr = s.to_repr();
+            let digit = scalar_window(repr.as_ref(), little_endian, offset, window);
+            if digit != 0 {
+                buckets[digit - 1] += *p;
+            }
+        }
+
+        // Σ digit * bucket[digit] using running sums
+        let mut running = G::identity();
+        let mut sum = G::identity();
+        for b in buckets.iter().take(num_buckets).rev() {
+            running += *b;
+            sum += running;
+        }
+        result += sum;
+    }
+    result
+}
+
+/// Read `width` bits of the scalar representation starting at bit `offset`
+fn scalar_window(repr: &[u8], little_endian: bool, offset: usize, width: usize) -> usize {
+    let mut digit = 0;
+    for j in 0..width {
+        let bit = offset + j;
+        if bit >= repr.len() * 8 {
+            break;
+        }
+        let byte = if little_endian {
+            repr[bit / 8]
+        } else {
+            repr[repr.len() - 1 - bit / 8]
+        };
+        digit |= (((byte >> (bit % 8)) & 1) as usize) << j;
+    }
+    digit
+}
+
+/// Compute the lagrange coefficient at zero for `identifier`
+/// given the set of `participants` taking part in the interpolation
+pub(crate) fn lagrange<F: PrimeField>(
+    identifier: u8,
+    participants: &[u8],
+) -> Result<F, SharingError> {
+    if identifier == 0 {
+        return Err(SharingError::SharingInvalidIdentifier);
+    }
+    let x_i = F::from(identifier as u64);
+    let mut num = F::one();
+    let mut den = F::one();
+    let mut found = false;
+    for (i, p) in participants.iter().enumerate() {
+        if *p == 0 {
+            return Err(SharingError::SharingInvalidIdentifier);
+        }
+        if participants[..i].contains(p) {
+            return Err(SharingError::SharingDuplicateIdentifier);
+        }
+        if *p == identifier {
+            found = true;
+            continue;
+        }
+        let x_j = F::from(*p as u64);
+        // x_j / (x_j - x_i) * ...
+        num *= x_j;
+        den *= x_j - x_i;
+    }
+    if !found {
+        return Err(SharingError::SharingInvalidIdentifier);
+    }
+    Ok(num * den.invert().unwrap())
+}
+
 pub(crate) fn deserialize_group<'de, G: Group + GroupEncoding, D: Deserializer<'de>>(
     d: D,
 ) -> Result<G, D::Error> {